@@ -0,0 +1,133 @@
+//! `webgen diff`: how far a project has drifted from its template, by
+//! regenerating it in memory with the recorded options.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use diffy::{DiffOptions, Line, Patch};
+
+use crate::git;
+use crate::manifest::{self, Manifest};
+use crate::templates::Template;
+
+/// Folders skipped when the project is not in a git repository (in one, its
+/// `.gitignore` decides).
+const SKIPPED_DIRS: &[&str] = &[
+    ".git",
+    "node_modules",
+    "venv",
+    ".venv",
+    "__pycache__",
+    ".pytest_cache",
+    ".next",
+    "build",
+    "dist",
+    "coverage",
+];
+
+pub struct ProjectDiff {
+    /// Generated files whose contents differ in the project.
+    pub modified: Vec<FileDiff>,
+    /// Files in the project that the template does not generate.
+    pub added: Vec<String>,
+    /// Generated files missing from the project.
+    pub deleted: Vec<String>,
+}
+
+pub struct FileDiff {
+    pub path: String,
+    pub generated: String,
+    /// `None` when the project's file is not UTF-8 text.
+    pub current: Option<String>,
+}
+
+impl FileDiff {
+    /// Unified diff from the generated file (`a/`) to the project's (`b/`).
+    pub fn patch(&self) -> Option<Patch<'_, str>> {
+        let current = self.current.as_deref()?;
+        let patch = DiffOptions::new()
+            .set_original_filename(format!("a/{}", self.path))
+            .set_modified_filename(format!("b/{}", self.path))
+            .create_patch(&self.generated, current);
+        Some(patch)
+    }
+
+    /// Lines added and removed in the project.
+    pub fn stat(&self) -> (usize, usize) {
+        let Some(patch) = self.patch() else {
+            return (0, 0);
+        };
+        let lines = patch.hunks().iter().flat_map(|hunk| hunk.lines());
+        lines.fold((0, 0), |(added, removed), line| match line {
+            Line::Insert(_) => (added + 1, removed),
+            Line::Delete(_) => (added, removed + 1),
+            Line::Context(_) => (added, removed),
+        })
+    }
+}
+
+impl ProjectDiff {
+    pub fn compute(dir: &Path, template: &dyn Template, manifest: &Manifest) -> Result<Self> {
+        let plan = manifest.regenerate(template)?;
+        let mut diff = Self { modified: Vec::new(), added: Vec::new(), deleted: Vec::new() };
+
+        for file in plan.files.iter().filter(|f| f.path != manifest::FILE_NAME) {
+            let path = dir.join(&file.path);
+            let current = match fs::read(&path) {
+                Ok(current) => current,
+                Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                    diff.deleted.push(file.path.clone());
+                    continue;
+                }
+                Err(e) => return Err(e).with_context(|| format!("Failed to read {}", path.display())),
+            };
+            if current != file.contents.as_bytes() {
+                diff.modified.push(FileDiff {
+                    path: file.path.clone(),
+                    generated: file.contents.clone(),
+                    current: String::from_utf8(current).ok(),
+                });
+            }
+        }
+
+        let mut files = if git::inside_work_tree(dir) {
+            git::list_files(dir)?
+        } else {
+            let mut files = Vec::new();
+            walk(dir, "", &mut files)?;
+            files
+        };
+        files.sort();
+        files.dedup();
+        diff.added = files
+            .into_iter()
+            .filter(|path| path != manifest::FILE_NAME && dir.join(path).is_file())
+            .filter(|path| !plan.files.iter().any(|f| f.path == *path))
+            .collect();
+
+        Ok(diff)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.modified.is_empty() && self.added.is_empty() && self.deleted.is_empty()
+    }
+}
+
+fn walk(dir: &Path, prefix: &str, files: &mut Vec<String>) -> Result<()> {
+    let entries = fs::read_dir(dir).with_context(|| format!("Failed to read {}", dir.display()))?;
+    for entry in entries {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let path = format!("{}{}", prefix, name);
+        if entry.file_type()?.is_dir() {
+            if !SKIPPED_DIRS.contains(&name.as_str()) {
+                walk(&entry.path(), &format!("{}/", path), files)?;
+            }
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
@@ -1,50 +1,368 @@
-use std::fs;
+use std::ffi::OsStr;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
 use std::path::Path;
-use anyhow::{Context, Result};
+use std::time::{SystemTime, UNIX_EPOCH};
 
-use crate::templates::*;
+use anyhow::{bail, Context, Result};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 
-pub fn generate_project(name: &str, template: &str, output_dir: &Path) -> Result<()> {
-    let project_path = output_dir.join(name);
-    
-    if project_path.exists() {
-        anyhow::bail!("Directory '{}' already exists!", name);
+use crate::output::{Archive, ArchiveFormat, Directory, Output};
+use crate::package_manager::PackageManager;
+use crate::plan::ProjectPlan;
+use crate::profile::Profile;
+use crate::render::{self, Variables};
+use crate::staging::Staging;
+use crate::templates::{Feature, Template};
+
+/// Everything besides the template and the project name that decides what
+/// gets generated.
+#[derive(Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Options {
+    /// Defaults to the profile's name, then to a placeholder.
+    pub author: Option<String>,
+    pub description: Option<String>,
+    pub typescript: bool,
+    pub css: Css,
+    pub profile: Option<Profile>,
+    pub package_manager: PackageManager,
+    pub docker: bool,
+    pub ci: Option<Ci>,
+    pub tests: bool,
+    /// Used by CI workflows and Docker images; defaults to [`NODE_VERSION`].
+    pub node_version: Option<String>,
+    /// Used by CI workflows and Docker images; defaults to [`PYTHON_VERSION`].
+    pub python_version: Option<String>,
+    /// `--var` overrides, applied after everything else.
+    pub vars: Map<String, Value>,
+}
+
+pub const NODE_VERSION: &str = "20";
+pub const PYTHON_VERSION: &str = "3.12";
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Css {
+    #[default]
+    Plain,
+    Tailwind,
+    Scss,
+}
+
+impl Css {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Css::Plain => "plain",
+            Css::Tailwind => "tailwind",
+            Css::Scss => "scss",
+        }
     }
+}
 
-    fs::create_dir_all(&project_path)
-        .context("Failed to create project directory")?;
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Ci {
+    Github,
+    Gitlab,
+}
 
-    match template {
-        "flask-portfolio" | "flask" => {
-            flask::generate(&project_path)?;
+impl Ci {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Ci::Github => "github",
+            Ci::Gitlab => "gitlab",
         }
-        "mern-stack" | "mern" => {
-            mern::generate(&project_path)?;
+    }
+}
+
+impl Options {
+    pub fn check(&self, template: &dyn Template) -> Result<()> {
+        let requested = [
+            (Feature::TypeScript, self.typescript),
+            (Feature::Css, self.css != Css::Plain),
+            (Feature::Profile, self.profile.is_some()),
+            (Feature::PackageManager, self.package_manager != PackageManager::Npm),
+            (Feature::Docker, self.docker),
+            (Feature::Ci, self.ci.is_some()),
+            (Feature::Tests, self.tests),
+        ];
+        for (feature, enabled) in requested {
+            if enabled && !template.features().contains(&feature) {
+                bail!(
+                    "Template '{}' does not support {}",
+                    template.id(),
+                    feature.flag()
+                );
+            }
         }
-        "next.js" | "nextjs" => {
-            nextjs::generate(&project_path)?;
+        Ok(())
+    }
+
+    /// The variables `template` is rendered with: the project's details, the
+    /// template's own defaults, these options and finally the overrides.
+    pub fn variables(&self, name: &str, template: &dyn Template) -> Variables {
+        let profile_name = self.profile.as_ref().and_then(|p| p.name.as_deref());
+        let mut vars = project_variables(
+            name,
+            self.author.as_deref().or(profile_name),
+            self.description.as_deref(),
+        );
+        for (key, value) in template.variables() {
+            vars.insert_default(&key, value);
         }
-        "react-portfolio" | "react" => {
-            react::generate(&project_path)?;
+        self.apply(&mut vars);
+        for (key, value) in &self.vars {
+            vars.insert(key, value.clone());
         }
-        "vue-portfolio" | "vue" => {
-            vue::generate(&project_path)?;
+        vars
+    }
+
+    pub fn apply(&self, vars: &mut Variables) {
+        vars.insert("typescript", self.typescript);
+        vars.insert("css", self.css.as_str());
+        vars.insert("tailwind", self.css == Css::Tailwind);
+        vars.insert("scss", self.css == Css::Scss);
+        vars.insert("css_ext", if self.css == Css::Scss { "scss" } else { "css" });
+        let pm = self.package_manager;
+        vars.insert("package_manager", pm.as_str());
+        vars.insert("package_manager_spec", pm.spec().unwrap_or_default());
+        vars.insert("install_cmd", pm.install_cmd());
+        vars.insert("run_cmd", pm.run_cmd());
+        vars.insert("start_cmd", pm.start_cmd());
+        vars.insert("docker", self.docker);
+        vars.insert("docker_install", pm.docker_install());
+        vars.insert("ci", self.ci.map(Ci::as_str).unwrap_or_default());
+        vars.insert("tests", self.tests);
+        vars.insert("github_setup", pm.github_setup().unwrap_or_default());
+        vars.insert("github_cache", pm.github_cache().unwrap_or_default());
+        vars.insert("setup_cmd", pm.setup_cmd().unwrap_or_default());
+        vars.insert("lockfile", pm.lockfile());
+        vars.insert("node_version", self.node_version.as_deref().unwrap_or(NODE_VERSION));
+        vars.insert("python_version", self.python_version.as_deref().unwrap_or(PYTHON_VERSION));
+        if let Some(profile) = &self.profile {
+            profile.apply(vars);
+        }
+    }
+}
+
+/// What to do when a generated file already exists in the target directory.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Conflicts {
+    /// Refuse to generate into an existing, non-empty directory.
+    Fail,
+    Overwrite,
+    Keep,
+    Sidecar,
+    /// Decide per file through the callback passed to `generate_project`.
+    Ask,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Resolution {
+    Overwrite,
+    Keep,
+    Sidecar,
+}
+
+pub const SIDECAR_SUFFIX: &str = ".webgen-new";
+
+#[derive(Default, Serialize)]
+pub struct Summary {
+    pub created: Vec<String>,
+    pub unchanged: Vec<String>,
+    pub overwritten: Vec<String>,
+    pub kept: Vec<String>,
+    pub sidecars: Vec<String>,
+}
+
+impl Summary {
+    pub fn had_conflicts(&self) -> bool {
+        !(self.overwritten.is_empty() && self.kept.is_empty() && self.sidecars.is_empty())
+    }
+}
+
+pub fn generate_project(
+    plan: &ProjectPlan,
+    project_path: &Path,
+    conflicts: Conflicts,
+    ask: &mut dyn FnMut(&str) -> Result<Resolution>,
+) -> Result<Summary> {
+    if project_path.exists() {
+        if !project_path.is_dir() {
+            bail!("'{}' already exists and is not a directory!", project_path.display());
         }
-        "static-html" | "static" => {
-            static_html::generate(&project_path)?;
+        let empty = fs::read_dir(project_path)?.next().is_none();
+        if !empty && conflicts == Conflicts::Fail {
+            bail!(
+                "Directory '{}' already exists! Use --merge, --force or --skip-existing to generate into it.",
+                project_path.display()
+            );
         }
-        _ => {
-            anyhow::bail!("Unknown template: {}", template);
+        return merge_into(plan, project_path, conflicts, ask);
+    }
+
+    let parent = match project_path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    fs::create_dir_all(parent)
+        .context("Failed to create output directory")?;
+
+    // Nothing appears at `project_path` until every file has been written.
+    let name = project_path.file_name().unwrap_or(OsStr::new(&plan.name));
+    let staging = Staging::new(parent, &name.to_string_lossy())?;
+    plan.render(&mut Directory::new(staging.path()))?;
+    staging.commit(project_path)?;
+
+    Ok(Summary {
+        created: plan.files.iter().map(|f| f.path.clone()).collect(),
+        ..Summary::default()
+    })
+}
+
+/// Writes the plan into a `.zip` or `.tar.gz` at `archive` instead of a
+/// directory, below a single folder named after the project.
+pub fn archive_project(plan: &ProjectPlan, archive: &Path, overwrite: bool) -> Result<Summary> {
+    let format = ArchiveFormat::from_path(archive).with_context(|| {
+        format!("Unsupported archive '{}': use .zip, .tar.gz or .tgz", archive.display())
+    })?;
+    if let Some(parent) = archive.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent).context("Failed to create output directory")?;
+    }
+    let file = if overwrite {
+        File::create(archive)
+    } else {
+        File::options().write(true).create_new(true).open(archive)
+    };
+    let file = match file {
+        Ok(file) => file,
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists => bail!(
+            "'{}' already exists! Use --force to overwrite it.",
+            archive.display()
+        ),
+        Err(e) => {
+            return Err(e).with_context(|| format!("Failed to create {}", archive.display()))
         }
+    };
+
+    let mut out = Archive::new(BufWriter::new(file), format, &plan.name);
+    let written = plan
+        .render(&mut out)
+        .and_then(|()| Ok(out.into_inner()?.flush()?));
+    if let Err(e) = written {
+        let _ = fs::remove_file(archive);
+        return Err(e.context(format!("Failed to write {}", archive.display())));
     }
 
-    Ok(())
+    Ok(Summary {
+        created: plan.files.iter().map(|f| f.path.clone()).collect(),
+        ..Summary::default()
+    })
 }
 
-pub fn write_file(path: &Path, content: &str) -> Result<()> {
-    if let Some(parent) = path.parent() {
-        fs::create_dir_all(parent)?;
+fn merge_into(
+    plan: &ProjectPlan,
+    project_path: &Path,
+    conflicts: Conflicts,
+    ask: &mut dyn FnMut(&str) -> Result<Resolution>,
+) -> Result<Summary> {
+    let mut summary = Summary::default();
+    let mut writes = Vec::new();
+
+    // Settle every conflict before touching the directory.
+    for file in &plan.files {
+        let dest = project_path.join(&file.path);
+        if !dest.exists() {
+            summary.created.push(file.path.clone());
+            writes.push((file, file.path.clone()));
+            continue;
+        }
+        if dest.is_dir() {
+            bail!("Cannot write {}: a directory with that name exists", file.path);
+        }
+        if fs::read(&dest)? == file.contents.as_bytes() {
+            summary.unchanged.push(file.path.clone());
+            continue;
+        }
+
+        let resolution = match conflicts {
+            Conflicts::Overwrite => Resolution::Overwrite,
+            Conflicts::Keep => Resolution::Keep,
+            Conflicts::Sidecar => Resolution::Sidecar,
+            Conflicts::Ask => ask(&file.path)?,
+            Conflicts::Fail => unreachable!("checked by generate_project"),
+        };
+        match resolution {
+            Resolution::Overwrite => {
+                summary.overwritten.push(file.path.clone());
+                writes.push((file, file.path.clone()));
+            }
+            Resolution::Keep => summary.kept.push(file.path.clone()),
+            Resolution::Sidecar => {
+                let sidecar = format!("{}{}", file.path, SIDECAR_SUFFIX);
+                summary.sidecars.push(sidecar.clone());
+                writes.push((file, sidecar));
+            }
+        }
+    }
+
+    // Stage inside the target so the final moves stay on one filesystem.
+    let name = project_path.file_name().unwrap_or(OsStr::new(&plan.name));
+    let staging = Staging::new(project_path, &name.to_string_lossy())?;
+    let mut out = Directory::new(staging.path());
+    for (file, dest) in &writes {
+        out.write(dest, &file.contents)
+            .with_context(|| format!("Failed to write {}", dest))?;
     }
-    fs::write(path, content)?;
-    Ok(())
+    staging.moving_out();
+    for (_, dest) in &writes {
+        let target = project_path.join(dest);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::rename(staging.path().join(dest), &target)
+            .with_context(|| format!("Failed to move {} into place", dest))?;
+    }
+
+    Ok(summary)
+}
+
+/// Parses a `--var key=value` argument. Values are read as JSON when they
+/// parse (`true`, `3`, `["a", "b"]`) and as plain strings otherwise.
+pub fn parse_var(arg: &str) -> Result<(String, Value)> {
+    let (key, value) = arg
+        .split_once('=')
+        .with_context(|| format!("Invalid --var '{}', expected key=value", arg))?;
+    let value = serde_json::from_str(value).unwrap_or_else(|_| Value::from(value));
+    Ok((key.trim().to_string(), value))
+}
+
+pub fn project_variables(name: &str, author: Option<&str>, description: Option<&str>) -> Variables {
+    let mut vars = Variables::new();
+    vars.insert("project_name", name);
+    vars.insert("package_name", render::slugify(name));
+    vars.insert("author", author.unwrap_or("Your Name"));
+    vars.insert("description", description.unwrap_or_default());
+    vars.insert("year", current_year());
+    vars
+}
+
+fn current_year() -> i64 {
+    let days = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() / 86_400)
+        .unwrap_or_default() as i64;
+
+    // Civil-from-days (Howard Hinnant), enough to stamp a copyright year.
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let year = yoe + era * 400;
+    if mp >= 10 { year + 1 } else { year }
 }
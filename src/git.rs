@@ -0,0 +1,117 @@
+use std::path::Path;
+use std::process::Command;
+use std::str::FromStr;
+
+use anyhow::{bail, Context, Result};
+
+pub const COMMIT_MESSAGE: &str = "Initial commit from webgen";
+
+/// A commit identity in git's own `Name <email>` form.
+#[derive(Clone, Debug)]
+pub struct Identity {
+    pub name: String,
+    pub email: String,
+}
+
+impl FromStr for Identity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("'{}' is not of the form \"Name <email>\"", s);
+        let (name, rest) = s.split_once('<').ok_or_else(invalid)?;
+        let email = rest.strip_suffix('>').ok_or_else(invalid)?.trim();
+        let name = name.trim();
+        if name.is_empty() || email.is_empty() {
+            return Err(invalid());
+        }
+        Ok(Identity { name: name.to_string(), email: email.to_string() })
+    }
+}
+
+pub enum Outcome {
+    /// A new repository with an initial commit (abbreviated hash).
+    Committed(String),
+    /// The project already lives in a work tree, which is left alone.
+    InsideRepository,
+}
+
+pub fn available() -> bool {
+    Command::new("git")
+        .arg("--version")
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
+
+/// Runs `git init`, stages everything the template's `.gitignore` lets through
+/// and makes the initial commit, as `author` if given or else with git's
+/// configured identity.
+pub fn init(path: &Path, author: Option<&Identity>) -> Result<Outcome> {
+    if inside_work_tree(path) {
+        return Ok(Outcome::InsideRepository);
+    }
+
+    git(path, &["init", "--quiet"], None)?;
+    git(path, &["add", "--all"], None)?;
+    git(path, &["commit", "--quiet", "-m", COMMIT_MESSAGE], author).context(
+        "Initialized a git repository but could not make the initial commit. \
+         Pass --git-author \"Name <email>\" or set git's user.name and user.email",
+    )?;
+    let hash = git(path, &["rev-parse", "--short", "HEAD"], None)?;
+    Ok(Outcome::Committed(hash))
+}
+
+pub fn inside_work_tree(path: &Path) -> bool {
+    Command::new("git")
+        .args(["rev-parse", "--is-inside-work-tree"])
+        .current_dir(path)
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
+
+/// Whether `path` has uncommitted changes, untracked files included.
+pub fn has_changes(path: &Path) -> Result<bool> {
+    Ok(!git(path, &["status", "--porcelain", "--", "."], None)?.is_empty())
+}
+
+/// Files below `path`, tracked or not, minus what `.gitignore` excludes.
+pub fn list_files(path: &Path) -> Result<Vec<String>> {
+    let files = git(path, &["ls-files", "--cached", "--others", "--exclude-standard", "--", "."], None)?;
+    Ok(files.lines().map(str::to_string).collect())
+}
+
+/// Commits that changed `file` (relative to `path`), newest first.
+pub fn revisions(path: &Path, file: &str) -> Result<Vec<String>> {
+    let log = git(path, &["log", "--format=%H", "--", file], None)?;
+    Ok(log.lines().map(str::to_string).collect())
+}
+
+/// `file`'s contents as of commit `rev`.
+pub fn show(path: &Path, rev: &str, file: &str) -> Result<Vec<u8>> {
+    run(path, &["show", &format!("{}:./{}", rev, file)], None)
+}
+
+fn git(path: &Path, args: &[&str], identity: Option<&Identity>) -> Result<String> {
+    let stdout = run(path, args, identity)?;
+    Ok(String::from_utf8_lossy(&stdout).trim().to_string())
+}
+
+fn run(path: &Path, args: &[&str], identity: Option<&Identity>) -> Result<Vec<u8>> {
+    let mut command = Command::new("git");
+    command.args(args).current_dir(path);
+    if let Some(identity) = identity {
+        for role in ["AUTHOR", "COMMITTER"] {
+            command.env(format!("GIT_{}_NAME", role), &identity.name);
+            command.env(format!("GIT_{}_EMAIL", role), &identity.email);
+        }
+    }
+    let output = command
+        .output()
+        .with_context(|| format!("Failed to run git {}", args[0]))?;
+    if !output.status.success() {
+        // git explains itself over several lines; the last one carries the reason.
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let reason = stderr.lines().rev().find(|line| !line.trim().is_empty()).unwrap_or_default();
+        bail!("git {} failed: {}", args[0], reason.trim());
+    }
+    Ok(output.stdout)
+}
@@ -0,0 +1,183 @@
+//! `--install`: installs dependencies next to every `package.json` and
+//! `requirements.txt` a template generated. Workspace members (mern's
+//! `backend/` and `frontend/`) are installed through their root.
+
+use std::io;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+use colored::*;
+
+use crate::package_manager::PackageManager;
+use crate::plan::PlannedFile;
+
+/// Installs the dependencies of the generated project, streaming the tools'
+/// output. Child stdout goes to stderr when `quiet` is set so that webgen's
+/// own stdout stays machine-readable. Returns one message per failed install.
+pub fn install(
+    project: &Path,
+    files: &[PlannedFile],
+    pm: PackageManager,
+    quiet: bool,
+) -> Vec<String> {
+    let workspaces = workspace_roots(files);
+    let mut failures = Vec::new();
+    for file in files {
+        let dir = split(&file.path).0;
+        // Workspace members are installed along with their root.
+        if workspaces.iter().any(|root| is_inside(dir, root)) {
+            continue;
+        }
+        let commands = match commands(project, &file.path, pm) {
+            Some(Ok(commands)) => commands,
+            None => continue,
+            Some(Err(reason)) => {
+                failures.push(format!("Skipped installing {}: {}", file.path, reason));
+                continue;
+            }
+        };
+        for mut command in commands {
+            let shown = display(&command, dir);
+            eprintln!("\n{} {}", "📦 Running".bright_cyan(), shown.bright_white());
+            if quiet {
+                command.stdout(Stdio::from(io::stderr()));
+            }
+            match command.status() {
+                Ok(status) if status.success() => {}
+                Ok(status) => {
+                    failures.push(format!("`{}` failed ({})", shown, status));
+                    break;
+                }
+                Err(e) => {
+                    failures.push(format!("Failed to run `{}`: {}", shown, e));
+                    break;
+                }
+            }
+        }
+    }
+    failures
+}
+
+/// Directories holding a `pnpm-workspace.yaml` or a package.json with `workspaces`.
+fn workspace_roots(files: &[PlannedFile]) -> Vec<&str> {
+    files
+        .iter()
+        .filter(|file| match split(&file.path).1 {
+            "pnpm-workspace.yaml" => true,
+            "package.json" => serde_json::from_str::<serde_json::Value>(&file.contents)
+                .is_ok_and(|json| json.get("workspaces").is_some()),
+            _ => false,
+        })
+        .map(|file| split(&file.path).0)
+        .collect()
+}
+
+fn split(path: &str) -> (&str, &str) {
+    path.rsplit_once('/').unwrap_or(("", path))
+}
+
+/// Whether `dir` is strictly below `root` ("" being the project root).
+fn is_inside(dir: &str, root: &str) -> bool {
+    dir != root
+        && (root.is_empty() || dir.strip_prefix(root).is_some_and(|rest| rest.starts_with('/')))
+}
+
+/// The commands installing the dependencies declared in `file`, or `None` if
+/// it is not a dependency manifest. Paths are absolute, as the commands run
+/// from the manifest's directory.
+fn commands(project: &Path, file: &str, pm: PackageManager) -> Option<Result<Vec<Command>, String>> {
+    let (dir, manifest) = split(file);
+    let commands = match manifest {
+        "package.json" => node_commands,
+        "requirements.txt" => |dir: &Path, _| python_commands(dir),
+        _ => return None,
+    };
+    Some(match std::path::absolute(project.join(dir)) {
+        Ok(dir) => commands(&dir, pm),
+        Err(e) => Err(e.to_string()),
+    })
+}
+
+fn node_commands(dir: &Path, pm: PackageManager) -> Result<Vec<Command>, String> {
+    if !on_path(pm.as_str()) {
+        return Err(format!("{} was not found on PATH", pm.as_str()));
+    }
+    let mut command = Command::new(pm.as_str());
+    command.arg("install").current_dir(dir);
+    Ok(vec![command])
+}
+
+/// `venv/` matches the activation step in the flask template's next steps.
+fn python_commands(dir: &Path) -> Result<Vec<Command>, String> {
+    if on_path("uv") {
+        let mut venv = Command::new("uv");
+        venv.args(["venv", "venv"]).current_dir(dir);
+        let mut pip = Command::new("uv");
+        pip.args(["pip", "install", "-r", "requirements.txt"])
+            .env("VIRTUAL_ENV", dir.join("venv"))
+            .current_dir(dir);
+        return Ok(vec![venv, pip]);
+    }
+
+    let python = ["python3", "python"]
+        .into_iter()
+        .find(|python| on_path(python))
+        .ok_or("no Python interpreter found (install Python 3 or uv)")?;
+    let pip = if cfg!(windows) { "venv/Scripts/pip" } else { "venv/bin/pip" };
+    let mut venv = Command::new(python);
+    venv.args(["-m", "venv", "venv"]).current_dir(dir);
+    let mut install = Command::new(dir.join(pip));
+    install.args(["install", "-r", "requirements.txt"]).current_dir(dir);
+    Ok(vec![venv, install])
+}
+
+fn on_path(program: &str) -> bool {
+    Command::new(program)
+        .arg("--version")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+/// `cd backend && npm install`, relative to the project root.
+fn display(command: &Command, dir: &str) -> String {
+    let program = Path::new(command.get_program());
+    let program = program.strip_prefix(command.get_current_dir().unwrap_or(Path::new("")))
+        .unwrap_or(program);
+    let mut shown = program.to_string_lossy().into_owned();
+    for arg in command.get_args() {
+        shown.push(' ');
+        shown.push_str(&arg.to_string_lossy());
+    }
+    if dir.is_empty() {
+        shown
+    } else {
+        format!("cd {} && {}", dir, shown)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn python_commands_for_a_relative_project_use_absolute_paths() {
+        let commands = commands(Path::new("site"), "api/requirements.txt", PackageManager::Npm)
+            .unwrap()
+            .unwrap();
+        let install = commands.last().unwrap();
+
+        let dir = install.get_current_dir().unwrap();
+        assert!(dir.is_absolute() && dir.ends_with("site/api"), "{}", dir.display());
+        if install.get_program() == "uv" {
+            let venv = install.get_envs().find(|(key, _)| *key == "VIRTUAL_ENV").unwrap().1;
+            assert_eq!(venv, Some(dir.join("venv").as_os_str()));
+            assert_eq!(display(install, "api"), "cd api && uv pip install -r requirements.txt");
+        } else {
+            assert!(Path::new(install.get_program()).is_absolute());
+            let pip = if cfg!(windows) { "venv/Scripts/pip" } else { "venv/bin/pip" };
+            assert_eq!(display(install, "api"), format!("cd api && {} install -r requirements.txt", pip));
+        }
+    }
+}
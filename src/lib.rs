@@ -0,0 +1,58 @@
+//! The generator behind the `webgen` CLI.
+//!
+//! A [`ProjectPlan`] is the rendered file tree of a project, built from a
+//! template id and [`Options`]. Nothing touches the disk until it is rendered
+//! into an [`Output`]: a [`Directory`](output::Directory), a
+//! `BTreeMap<String, String>` or an [`Archive`](output::Archive).
+//!
+//! ```
+//! use std::collections::BTreeMap;
+//! use webgen::{Css, Options};
+//!
+//! let options = Options { typescript: true, css: Css::Tailwind, ..Options::default() };
+//! let plan = webgen::plan("react", "my-site", &options)?;
+//!
+//! let mut files = BTreeMap::new();
+//! plan.render(&mut files)?;
+//! assert!(files.contains_key("src/App.tsx"));
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+//!
+//! [`generator::generate_project`] writes a plan to disk the way `webgen new`
+//! does, staging it first and resolving conflicts with existing files.
+
+use anyhow::{Context, Result};
+
+pub mod diff;
+pub mod generator;
+pub mod git;
+pub mod install;
+pub mod manifest;
+pub mod output;
+pub mod package_manager;
+pub mod plan;
+pub mod profile;
+pub mod render;
+pub mod resume;
+pub mod staging;
+pub mod templates;
+pub mod upgrade;
+
+pub use generator::{Ci, Css, Options};
+pub use package_manager::PackageManager;
+pub use manifest::Manifest;
+pub use output::{Archive, ArchiveFormat, Output};
+pub use plan::{PlannedFile, ProjectPlan};
+pub use templates::{Registry, Template};
+
+/// The version recorded in project manifests.
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Plans the built-in template `template` (an id or alias) for a project called `name`.
+pub fn plan(template: &str, name: &str, options: &Options) -> Result<ProjectPlan> {
+    let registry = Registry::builtin();
+    let template = registry
+        .find(template)
+        .with_context(|| format!("Unknown template: {}", template))?;
+    ProjectPlan::new(name, template, options)
+}
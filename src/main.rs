@@ -1,15 +1,27 @@
-use clap::{Parser, Subcommand};
+use clap::builder::FalseyValueParser;
+use clap::{Args, FromArgMatches, Parser, Subcommand, ValueEnum};
 use colored::*;
-use dialoguer::{theme::ColorfulTheme, Select};
-use std::path::PathBuf;
+use dialoguer::{theme::ColorfulTheme, Confirm, Select};
+use diffy::PatchFormatter;
+use serde::Serialize;
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
 
-mod templates;
-mod generator;
+use webgen::diff::ProjectDiff;
+use webgen::generator::{self, Ci, Conflicts, Css, Options, Resolution, Summary, SIDECAR_SUFFIX};
+use webgen::install;
+use webgen::package_manager::PackageManager;
+use webgen::plan::ProjectPlan;
+use webgen::profile::Profile;
+use webgen::templates::custom::CustomTemplate;
+use webgen::templates::{Feature, Registry, Template};
+use webgen::upgrade::{Upgrade, UpgradeSummary};
+use webgen::{git, resume, staging, ArchiveFormat, Manifest};
 
 #[derive(Parser)]
 #[command(name = "webgen")]
 #[command(author = "sumit007-ui")]
-#[command(version = "1.0.0")]
+#[command(version)]
 #[command(about = "Generate pre-built website templates for various tech stacks", long_about = None)]
 struct Cli {
     #[command(subcommand)]
@@ -19,32 +31,268 @@ struct Cli {
 #[derive(Subcommand)]
 enum Commands {
     /// Create a new project from a template
-    New {
-        /// Project name
-        name: Option<String>,
-        
-        /// Template type (flask, mern, nextjs, react, vue, portfolio)
-        #[arg(short, long)]
-        template: Option<String>,
-        
-        /// Output directory
-        #[arg(short, long)]
-        output: Option<PathBuf>,
-    },
+    New(NewArgs),
+
+    /// Scaffold a template into the current directory
+    Init(InitArgs),
     
     /// List all available templates
-    List,
+    List(ListArgs),
+
+    /// Re-apply the project's template from this version of webgen, merging
+    /// its changes into your files
+    Upgrade(UpgradeArgs),
+
+    /// Show how a project differs from what its template generates today
+    Diff(DiffArgs),
+}
+
+#[derive(Args)]
+struct DiffArgs {
+    /// Project directory (must contain a .webgen.json)
+    #[arg(default_value = ".")]
+    path: PathBuf,
+
+    /// Use the template in this directory instead of looking its id up
+    #[arg(long)]
+    template_dir: Option<PathBuf>,
+
+    /// Only show a summary of changed, added and deleted files
+    #[arg(long)]
+    stat: bool,
+}
+
+#[derive(Args)]
+struct UpgradeArgs {
+    /// Project directory (must contain a .webgen.json)
+    #[arg(default_value = ".")]
+    path: PathBuf,
+
+    /// Use the template in this directory instead of looking its id up
+    #[arg(long)]
+    template_dir: Option<PathBuf>,
+
+    /// Show what would change without writing anything
+    #[arg(long)]
+    dry_run: bool,
+
+    /// Upgrade even if the project has uncommitted changes
+    #[arg(long)]
+    force: bool,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
+}
+
+#[derive(Args)]
+struct ListArgs {
+    /// Output format
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
+}
+
+#[derive(Args, Default)]
+struct NewArgs {
+    /// Project name
+    #[arg(env = "WEBGEN_NAME")]
+    name: Option<String>,
+
+    /// Output directory
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// Write the project into a .zip, .tar.gz or .tgz archive instead of a directory
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["output", "merge", "skip_existing"])]
+    archive: Option<PathBuf>,
+
+    #[command(flatten)]
+    template: TemplateArgs,
+}
+
+#[derive(Args, Default)]
+struct InitArgs {
+    /// Project name (defaults to the current folder's name)
+    #[arg(long, env = "WEBGEN_NAME")]
+    name: Option<String>,
+
+    #[command(flatten)]
+    template: TemplateArgs,
+}
+
+#[derive(Args, Default)]
+struct TemplateArgs {
+    /// Template id or alias (see `webgen list`)
+    #[arg(short, long, env = "WEBGEN_TEMPLATE")]
+    template: Option<String>,
+
+    /// Use the template in this directory (must contain a template.toml)
+    #[arg(long, conflicts_with = "template")]
+    template_dir: Option<PathBuf>,
+
+    /// Author name used in the generated content
+    #[arg(long, env = "WEBGEN_AUTHOR")]
+    author: Option<String>,
+
+    /// Short project description
+    #[arg(long, env = "WEBGEN_DESCRIPTION")]
+    description: Option<String>,
+
+    /// Generate TypeScript instead of JavaScript (react, nextjs, vue, mern)
+    #[arg(
+        long,
+        env = "WEBGEN_TYPESCRIPT",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true"
+    )]
+    typescript: Option<bool>,
+
+    /// Styling approach (react, nextjs, vue, mern, static)
+    #[arg(long, value_enum, env = "WEBGEN_CSS")]
+    css: Option<Css>,
+
+    /// Package manager used in the generated scripts, READMEs and instructions
+    /// (defaults to the one webgen was launched through, else npm)
+    #[arg(long, value_enum, env = "WEBGEN_PACKAGE_MANAGER")]
+    package_manager: Option<PackageManager>,
+
+    /// Add a Dockerfile (and docker-compose.yml for mern) with a .dockerignore
+    #[arg(long, env = "WEBGEN_DOCKER", value_parser = FalseyValueParser::new())]
+    docker: bool,
+
+    /// Add a CI workflow for GitHub Actions or GitLab CI
+    #[arg(long, value_enum, env = "WEBGEN_CI")]
+    ci: Option<Ci>,
+
+    /// Add a test setup with sample tests for the generated code
+    #[arg(long, env = "WEBGEN_TESTS", value_parser = FalseyValueParser::new())]
+    tests: bool,
+
+    /// Node.js version for CI workflows and Docker images [default: 20]
+    #[arg(long, value_name = "VERSION", env = "WEBGEN_NODE_VERSION")]
+    node_version: Option<String>,
+
+    /// Python version for CI workflows and Docker images [default: 3.12]
+    #[arg(long, value_name = "VERSION", env = "WEBGEN_PYTHON_VERSION")]
+    python_version: Option<String>,
+
+    /// Personal details for the portfolio templates (.yaml, .json or .toml)
+    #[arg(long, value_name = "FILE", env = "WEBGEN_PROFILE")]
+    profile: Option<PathBuf>,
+
+    /// Fill the portfolio templates from a JSON Resume file (jsonresume.org)
+    #[arg(long, value_name = "FILE", env = "WEBGEN_RESUME", conflicts_with = "profile")]
+    from_resume: Option<PathBuf>,
+
+    /// Set a template variable (repeatable), e.g. --var api_url=http://localhost:8080
+    #[arg(long = "var", value_name = "KEY=VALUE")]
+    vars: Vec<String>,
+
+    /// Overwrite files that already exist in the target directory
+    #[arg(long, group = "existing")]
+    force: bool,
+
+    /// Generate into an existing directory, asking what to do with each conflicting file
+    /// (writes `<file>.webgen-new` instead when prompts are disabled)
+    #[arg(long, group = "existing")]
+    merge: bool,
+
+    /// Keep files that already exist in the target directory
+    #[arg(long, group = "existing")]
+    skip_existing: bool,
+
+    /// Show the files that would be generated without writing anything
+    #[arg(long)]
+    dry_run: bool,
+
+    /// Install dependencies after generating (npm, pnpm, yarn or bun; venv + pip or uv for Python)
+    #[arg(long, env = "WEBGEN_INSTALL", value_parser = FalseyValueParser::new())]
+    install: bool,
+
+    /// Initialize a git repository with an initial commit (on by default; --git=false to skip)
+    #[arg(
+        long,
+        env = "WEBGEN_GIT",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true"
+    )]
+    git: Option<bool>,
+
+    /// Author of the initial commit, e.g. "Jane Doe <jane@example.com>"
+    /// (defaults to git's user.name and user.email)
+    #[arg(long, value_name = "NAME <EMAIL>", env = "WEBGEN_GIT_AUTHOR")]
+    git_author: Option<git::Identity>,
+
+    /// Never prompt: use defaults and fail on anything that has none
+    /// (implied when stdin is not a terminal)
+    #[arg(
+        short = 'y',
+        long = "yes",
+        visible_alias = "no-input",
+        env = "WEBGEN_NO_INPUT",
+        value_parser = FalseyValueParser::new()
+    )]
+    no_input: bool,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
+}
+
+impl TemplateArgs {
+    fn interactive(&self) -> bool {
+        !self.no_input && std::io::stdin().is_terminal()
+    }
+
+    fn conflicts(&self, default: Conflicts) -> Conflicts {
+        if self.force {
+            Conflicts::Overwrite
+        } else if self.skip_existing {
+            Conflicts::Keep
+        } else if self.merge {
+            self.merge_conflicts()
+        } else {
+            default
+        }
+    }
+
+    fn merge_conflicts(&self) -> Conflicts {
+        if self.interactive() {
+            Conflicts::Ask
+        } else {
+            Conflicts::Sidecar
+        }
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    #[default]
+    Text,
+    Json,
 }
 
 fn main() -> anyhow::Result<()> {
     let cli = Cli::parse();
+    staging::clean_up_on_interrupt();
 
-    match &cli.command {
-        Some(Commands::New { name, template, output }) => {
-            handle_new_project(name.clone(), template.clone(), output.clone())?;
+    match cli.command {
+        Some(Commands::New(args)) => {
+            handle_new_project(args)?;
+        }
+        Some(Commands::Init(args)) => {
+            handle_init(args)?;
+        }
+        Some(Commands::List(args)) => {
+            list_templates(args.format)?;
+        }
+        Some(Commands::Upgrade(args)) => {
+            upgrade_project(args)?;
         }
-        Some(Commands::List) => {
-            list_templates();
+        Some(Commands::Diff(args)) => {
+            diff_project(args)?;
         }
         None => {
             // Interactive mode
@@ -55,103 +303,627 @@ fn main() -> anyhow::Result<()> {
     Ok(())
 }
 
-fn handle_new_project(
-    name: Option<String>,
-    template: Option<String>,
-    output: Option<PathBuf>,
+fn handle_new_project(args: NewArgs) -> anyhow::Result<()> {
+    let project_name = match args.name {
+        Some(name) => name,
+        None if args.template.interactive() => {
+            dialoguer::Input::with_theme(&ColorfulTheme::default())
+                .with_prompt("Project name")
+                .default(DEFAULT_NAME.to_string())
+                .interact()?
+        }
+        None => DEFAULT_NAME.to_string(),
+    };
+
+    if let Some(archive) = &args.archive {
+        if ArchiveFormat::from_path(archive).is_none() {
+            anyhow::bail!("Unsupported archive '{}': use .zip, .tar.gz or .tgz", archive.display());
+        }
+        if args.template.install {
+            anyhow::bail!("--install cannot be combined with --archive");
+        }
+    }
+
+    let output_dir = args.output.unwrap_or_else(|| PathBuf::from("."));
+    let project_path = output_dir.join(&project_name);
+
+    generate(
+        &project_name,
+        &project_path,
+        &args.template,
+        Conflicts::Fail,
+        Some(&project_name),
+        args.archive.as_deref(),
+    )
+}
+
+const DEFAULT_NAME: &str = "my-website";
+
+// Entries a freshly created repository may already have.
+const INIT_ALLOWED: &[&str] = &[".git", ".hg", ".svn", ".gitignore", ".gitattributes"];
+const INIT_ALLOWED_PREFIXES: &[&str] = &["license", "licence", "copying", "readme"];
+
+fn handle_init(args: InitArgs) -> anyhow::Result<()> {
+    let cwd = std::env::current_dir()?;
+    let project_name = match args.name {
+        Some(name) => name,
+        None => cwd
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .ok_or_else(|| anyhow::anyhow!("Cannot infer a project name here, pass --name"))?,
+    };
+
+    let mut unexpected = Vec::new();
+    for entry in std::fs::read_dir(&cwd)? {
+        let name = entry?.file_name().to_string_lossy().into_owned();
+        let lower = name.to_lowercase();
+        if !INIT_ALLOWED.contains(&lower.as_str())
+            && !INIT_ALLOWED_PREFIXES.iter().any(|p| lower.starts_with(p))
+        {
+            unexpected.push(name);
+        }
+    }
+    unexpected.sort();
+
+    let t = &args.template;
+    let opted_in = t.force || t.merge || t.skip_existing;
+    if !unexpected.is_empty() && !t.dry_run && !opted_in {
+        eprintln!(
+            "{} The current directory already contains: {}",
+            "⚠".yellow(),
+            unexpected.join(", ")
+        );
+        if !t.interactive() {
+            anyhow::bail!("Refusing to initialise a non-empty directory. Pass --merge, --force or --skip-existing to continue.");
+        }
+        let proceed = Confirm::with_theme(&ColorfulTheme::default())
+            .with_prompt("Generate into it anyway?")
+            .default(false)
+            .interact()?;
+        if !proceed {
+            anyhow::bail!("Aborted");
+        }
+    }
+
+    generate(&project_name, Path::new("."), t, t.merge_conflicts(), None, None)
+}
+
+fn generate(
+    project_name: &str,
+    project_path: &Path,
+    args: &TemplateArgs,
+    default_conflicts: Conflicts,
+    cd: Option<&str>,
+    archive: Option<&Path>,
 ) -> anyhow::Result<()> {
-    let project_name = name.unwrap_or_else(|| {
-        dialoguer::Input::with_theme(&ColorfulTheme::default())
-            .with_prompt("Project name")
-            .default("my-website".to_string())
-            .interact()
-            .unwrap()
-    });
-
-    let template_type = if let Some(t) = template {
-        t
-    } else {
-        let templates = templates::get_available_templates();
+    let registry = Registry::load();
+    let custom;
+    let interactive = args.interactive();
+    let json = args.format == OutputFormat::Json;
+    let mut warnings = registry.warnings.clone();
+    if !json {
+        warnings.drain(..).for_each(|w| warn(&w));
+    }
+
+    let template: &dyn Template = if let Some(dir) = &args.template_dir {
+        custom = CustomTemplate::load(dir)?;
+        &custom
+    } else if let Some(t) = &args.template {
+        registry
+            .find(t)
+            .ok_or_else(|| anyhow::anyhow!("Unknown template: {} (run `webgen list` to see all)", t))?
+    } else if interactive {
+        let choices: Vec<&dyn Template> = registry.iter().collect();
+        let names: Vec<&str> = choices.iter().map(|t| t.display_name()).collect();
         let selection = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Choose a template")
-            .items(&templates)
+            .items(&names)
             .default(0)
-            .interact()
-            .unwrap();
-        templates[selection].to_lowercase().replace(" ", "-")
+            .interact()?;
+        choices[selection]
+    } else {
+        anyhow::bail!(
+            "No template given and prompts are disabled. Pass --template <ID> or set WEBGEN_TEMPLATE (run `webgen list` to see all)"
+        );
     };
 
-    let output_dir = output.unwrap_or_else(|| PathBuf::from("."));
+    let supports = |feature| template.features().contains(&feature);
+    // Options::check would name --profile, which the resume is loaded into.
+    if args.from_resume.is_some() && !supports(Feature::Profile) {
+        anyhow::bail!("Template '{}' does not support --from-resume", template.id());
+    }
 
-    println!("\n{}", "🚀 Generating your project...".bright_cyan().bold());
-    println!("   📁 Name: {}", project_name.bright_white());
-    println!("   🎨 Template: {}", template_type.bright_white());
-    println!();
+    let options = Options {
+        typescript: match args.typescript {
+            Some(typescript) => typescript,
+            None if interactive && supports(Feature::TypeScript) => {
+                Confirm::with_theme(&ColorfulTheme::default())
+                    .with_prompt("Use TypeScript?")
+                    .default(false)
+                    .interact()?
+            }
+            None => false,
+        },
+        css: match args.css {
+            Some(css) => css,
+            None if interactive && supports(Feature::Css) => {
+                let choices = [Css::Plain, Css::Tailwind, Css::Scss];
+                let selection = Select::with_theme(&ColorfulTheme::default())
+                    .with_prompt("Styling")
+                    .items(&["Plain CSS", "Tailwind CSS", "SCSS"])
+                    .default(0)
+                    .interact()?;
+                choices[selection]
+            }
+            None => Css::Plain,
+        },
+        profile: match (&args.profile, &args.from_resume) {
+            (Some(path), _) => Some(Profile::load(path)?),
+            (None, Some(path)) => Some(resume::load(path)?),
+            (None, None) => None,
+        },
+        package_manager: match args.package_manager {
+            Some(pm) => pm,
+            None if supports(Feature::PackageManager) => {
+                PackageManager::detect(project_path).unwrap_or_default()
+            }
+            None => PackageManager::Npm,
+        },
+        docker: args.docker,
+        ci: args.ci,
+        tests: args.tests,
+        node_version: args.node_version.clone(),
+        python_version: args.python_version.clone(),
+        author: args.author.clone(),
+        description: args.description.clone(),
+        vars: args.vars.iter().map(|var| generator::parse_var(var)).collect::<anyhow::Result<_>>()?,
+    };
+    let plan = ProjectPlan::new(project_name, template, &options)?;
+    let mut vars = options.variables(project_name, template);
+
+    if args.dry_run {
+        match args.format {
+            OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&plan)?),
+            OutputFormat::Text => {
+                println!();
+                plan.print_tree();
+                println!("\n{}", "Dry run: nothing was written.".bright_yellow());
+            }
+        }
+        return Ok(());
+    }
+
+    if !json {
+        println!("\n{}", "🚀 Generating your project...".bright_cyan().bold());
+        println!("   📁 Name: {}", project_name.bright_white());
+        println!("   🎨 Template: {}", template.display_name().bright_white());
+        println!();
+    }
+
+    let summary = match archive {
+        Some(archive) => generator::archive_project(&plan, archive, args.force)?,
+        None => generator::generate_project(
+            &plan,
+            project_path,
+            args.conflicts(default_conflicts),
+            &mut ask_conflict(),
+        )?,
+    };
+
+    // Before git, so that lockfiles end up in the initial commit.
+    if args.install {
+        let failures =
+            install::install(project_path, &plan.files, options.package_manager, json);
+        if failures.is_empty() {
+            vars.insert("installed", true);
+        } else {
+            warnings.extend(failures);
+            warnings.push(format!(
+                "The project was generated in {}; install its dependencies by hand",
+                project_path.display()
+            ));
+        }
+    }
+
+    let commit = if archive.is_some() || !args.git.unwrap_or(true) {
+        None
+    } else if !git::available() {
+        warnings.push("git was not found on PATH; skipping repository setup".to_string());
+        None
+    } else {
+        match git::init(project_path, args.git_author.as_ref()) {
+            Ok(git::Outcome::Committed(hash)) => Some(hash),
+            Ok(git::Outcome::InsideRepository) => None,
+            Err(e) => {
+                warnings.push(format!("{:#}", e));
+                None
+            }
+        }
+    };
 
-    generator::generate_project(&project_name, &template_type, &output_dir)?;
+    let mut next_steps: Vec<String> = archive.map(extract_step).into_iter().collect();
+    next_steps.extend(cd.map(|dir| format!("cd {}", dir)));
+    next_steps.extend(template.next_steps(&vars));
+
+    if json {
+        if !summary.sidecars.is_empty() {
+            warnings.push(format!(
+                "{} existing file(s) were left untouched; review the {} copies",
+                summary.sidecars.len(),
+                SIDECAR_SUFFIX
+            ));
+        }
+        let report = NewReport {
+            name: project_name,
+            template: template.id(),
+            path: archive.unwrap_or(project_path),
+            files: &summary,
+            commit,
+            next_steps,
+            warnings,
+        };
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+
+    if summary.had_conflicts() || !summary.unchanged.is_empty() {
+        print_summary(&summary);
+    }
+    warnings.drain(..).for_each(|w| warn(&w));
+    if let Some(hash) = commit {
+        println!("\n   🌱 Initialized a git repository ({} \"{}\")", hash, git::COMMIT_MESSAGE);
+    }
+    if let Some(archive) = archive {
+        println!("\n   📦 Wrote {} files to {}", summary.created.len(), archive.display());
+    }
 
     println!("\n{}", "✨ Project created successfully!".bright_green().bold());
     println!("\n{}", "Next steps:".bright_yellow().bold());
-    println!("   cd {}", project_name);
-    
-    match template_type.as_str() {
-        "flask" | "flask-portfolio" => {
-            println!("   python -m venv venv");
-            println!("   source venv/bin/activate  # On Windows: venv\\Scripts\\activate");
-            println!("   pip install -r requirements.txt");
-            println!("   python app.py");
+    for step in next_steps {
+        println!("   {}", step);
+    }
+
+    Ok(())
+}
+
+/// How to unpack what `--archive` wrote.
+fn extract_step(archive: &Path) -> String {
+    match ArchiveFormat::from_path(archive) {
+        Some(ArchiveFormat::Zip) => format!("unzip {}", archive.display()),
+        _ => format!("tar -xzf {}", archive.display()),
+    }
+}
+
+#[derive(Serialize)]
+struct NewReport<'a> {
+    name: &'a str,
+    template: &'a str,
+    path: &'a Path,
+    files: &'a Summary,
+    commit: Option<String>,
+    next_steps: Vec<String>,
+    warnings: Vec<String>,
+}
+
+fn ask_conflict() -> impl FnMut(&str) -> anyhow::Result<Resolution> {
+    let mut remembered = None;
+    move |path| {
+        if let Some(resolution) = remembered {
+            return Ok(resolution);
         }
-        "mern" | "mern-stack" => {
-            println!("   # Backend:");
-            println!("   cd backend && npm install && npm start");
-            println!("   # Frontend:");
-            println!("   cd frontend && npm install && npm start");
+        let items = [
+            "Overwrite".to_string(),
+            "Keep existing".to_string(),
+            format!("Write {}{}", path, SIDECAR_SUFFIX),
+            "Overwrite all remaining".to_string(),
+            "Keep all remaining".to_string(),
+            format!("Write {} for all remaining", SIDECAR_SUFFIX),
+        ];
+        let choice = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt(format!("{} already exists", path))
+            .items(&items)
+            .default(2)
+            .interact()?;
+        let resolution = [Resolution::Overwrite, Resolution::Keep, Resolution::Sidecar][choice % 3];
+        if choice >= 3 {
+            remembered = Some(resolution);
         }
-        "nextjs" | "next.js" => {
-            println!("   npm install");
-            println!("   npm run dev");
+        Ok(resolution)
+    }
+}
+
+fn print_summary(summary: &Summary) {
+    println!("\n{}", "Summary:".bright_yellow().bold());
+    println!(
+        "   {} created, {} overwritten, {} kept, {} written as {}, {} unchanged",
+        summary.created.len(),
+        summary.overwritten.len(),
+        summary.kept.len(),
+        summary.sidecars.len(),
+        SIDECAR_SUFFIX,
+        summary.unchanged.len()
+    );
+    for path in &summary.overwritten {
+        println!("   {} {}", "overwritten".yellow(), path);
+    }
+    for path in &summary.kept {
+        println!("   {} {}", "kept".cyan(), path);
+    }
+    for path in &summary.sidecars {
+        println!("   {} {}", "new".green(), path);
+    }
+}
+
+#[derive(Serialize)]
+struct UpgradeReport<'a> {
+    name: &'a str,
+    template: &'a str,
+    from_version: &'a str,
+    to_version: &'a str,
+    dry_run: bool,
+    files: &'a UpgradeSummary,
+}
+
+/// The template a project's manifest names, or the one in `template_dir`.
+fn recorded_template<'a>(
+    manifest: &Manifest,
+    template_dir: Option<&Path>,
+    registry: &'a Registry,
+    custom: &'a mut Option<CustomTemplate>,
+) -> anyhow::Result<&'a dyn Template> {
+    if let Some(dir) = template_dir {
+        return Ok(custom.insert(CustomTemplate::load(dir)?));
+    }
+    registry.find(&manifest.template).ok_or_else(|| {
+        anyhow::anyhow!(
+            "Unknown template: {} (pass --template-dir if it is a local template)",
+            manifest.template
+        )
+    })
+}
+
+fn upgrade_project(args: UpgradeArgs) -> anyhow::Result<()> {
+    let manifest = Manifest::load(&args.path)?;
+    let registry = Registry::load();
+    let mut custom = None;
+    let template =
+        recorded_template(&manifest, args.template_dir.as_deref(), &registry, &mut custom)?;
+
+    if !args.dry_run && !args.force && git::inside_work_tree(&args.path) && git::has_changes(&args.path)? {
+        anyhow::bail!(
+            "{} has uncommitted changes. Commit or stash them first so the upgrade can be reviewed and undone, or pass --force.",
+            args.path.display()
+        );
+    }
+
+    let upgrade = Upgrade::prepare(&args.path, template, &manifest)?;
+    if !args.dry_run {
+        upgrade.apply(&args.path)?;
+    }
+    let summary = &upgrade.summary;
+
+    if args.format == OutputFormat::Json {
+        let report = UpgradeReport {
+            name: &manifest.name,
+            template: &manifest.template,
+            from_version: &upgrade.from_version,
+            to_version: webgen::VERSION,
+            dry_run: args.dry_run,
+            files: summary,
+        };
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+
+    println!(
+        "\n{} {} ({}) from webgen {} to {}",
+        "⬆️  Upgrading".bright_cyan().bold(),
+        manifest.name.bright_white(),
+        template.display_name(),
+        upgrade.from_version,
+        webgen::VERSION
+    );
+    if summary.is_empty() {
+        println!("\n{}", "✨ Already up to date.".bright_green().bold());
+        return Ok(());
+    }
+    println!();
+    let sections = [
+        ("added", &summary.added),
+        ("updated", &summary.updated),
+        ("merged", &summary.merged),
+        ("conflict", &summary.conflicts),
+        ("removed", &summary.removed),
+        ("kept", &summary.kept),
+    ];
+    for (label, paths) in sections {
+        for path in paths {
+            let label = match label {
+                "conflict" => label.red().bold(),
+                "kept" => label.cyan(),
+                _ => label.green(),
+            };
+            println!("   {:>8} {}", label, path);
         }
-        "react" | "react-portfolio" => {
-            println!("   npm install");
-            println!("   npm start");
+    }
+    if !summary.kept.is_empty() {
+        println!(
+            "\n   {}",
+            "kept: files you deleted or customised that the template changed; see `webgen diff`".dimmed()
+        );
+    }
+
+    if args.dry_run {
+        println!("\n{}", "Dry run: nothing was written.".bright_yellow());
+    } else if !summary.conflicts.is_empty() {
+        println!(
+            "\n{} Resolve the conflict markers in {} file(s), then commit.",
+            "⚠".yellow(),
+            summary.conflicts.len()
+        );
+    } else {
+        println!("\n{}", "✨ Project upgraded! Review the changes and commit them.".bright_green().bold());
+    }
+    Ok(())
+}
+
+fn diff_project(args: DiffArgs) -> anyhow::Result<()> {
+    let manifest = Manifest::load(&args.path)?;
+    let registry = Registry::load();
+    let mut custom = None;
+    let template =
+        recorded_template(&manifest, args.template_dir.as_deref(), &registry, &mut custom)?;
+    let diff = ProjectDiff::compute(&args.path, template, &manifest)?;
+
+    if diff.is_empty() {
+        println!("No differences from the {} template.", template.display_name());
+        return Ok(());
+    }
+    if args.stat {
+        print_stat(&diff);
+        return Ok(());
+    }
+
+    let color = std::io::stdout().is_terminal() && colored::control::SHOULD_COLORIZE.should_colorize();
+    let formatter = if color { PatchFormatter::new().with_color() } else { PatchFormatter::new() };
+    for file in &diff.modified {
+        match file.patch() {
+            Some(patch) => print!("{}", formatter.fmt_patch(&patch)),
+            None => println!("Binary files a/{0} and b/{0} differ", file.path),
         }
-        "vue" | "vue-portfolio" => {
-            println!("   npm install");
-            println!("   npm run dev");
+    }
+    for (heading, paths) in [("Added in the project:", &diff.added), ("Deleted from the project:", &diff.deleted)] {
+        if !paths.is_empty() {
+            println!("\n{}", heading.bold());
+            paths.iter().for_each(|path| println!("   {}", path));
         }
-        _ => {
-            println!("   Check README.md for setup instructions");
+    }
+    Ok(())
+}
+
+/// A `git diff --stat` style summary, with added and deleted files listed by name.
+fn print_stat(diff: &ProjectDiff) {
+    const BAR_WIDTH: usize = 40;
+    let stats: Vec<_> = diff.modified.iter().map(|file| (file, file.stat())).collect();
+    let width = diff
+        .modified
+        .iter()
+        .map(|file| &file.path)
+        .chain(&diff.added)
+        .chain(&diff.deleted)
+        .map(|path| path.chars().count())
+        .max()
+        .unwrap_or_default();
+    let most = stats.iter().map(|(_, (added, removed))| added + removed).max().unwrap_or_default();
+
+    for (file, (added, removed)) in &stats {
+        let changes = added + removed;
+        let (plus, minus) = if most > BAR_WIDTH {
+            let scale = |n: usize| if n == 0 { 0 } else { (n * BAR_WIDTH / most).max(1) };
+            (scale(*added), scale(*removed))
+        } else {
+            (*added, *removed)
+        };
+        let bar = format!("{}{}", "+".repeat(plus).green(), "-".repeat(minus).red());
+        match file.current {
+            Some(_) => println!(" {:<width$} | {:>5} {}", file.path, changes, bar),
+            None => println!(" {:<width$} | {:>5}", file.path, "Bin"),
         }
     }
+    for path in &diff.added {
+        println!(" {:<width$} | {:>5}", path, "added".green());
+    }
+    for path in &diff.deleted {
+        println!(" {:<width$} | {:>5}", path, "deleted".red());
+    }
 
-    Ok(())
+    let insertions: usize = stats.iter().map(|(_, (added, _))| added).sum();
+    let deletions: usize = stats.iter().map(|(_, (_, removed))| removed).sum();
+    println!(
+        " {} file(s) changed, {} insertion(s)(+), {} deletion(s)(-), {} added, {} deleted",
+        diff.modified.len(),
+        insertions,
+        deletions,
+        diff.added.len(),
+        diff.deleted.len()
+    );
+}
+
+#[derive(Serialize)]
+struct ListReport<'a> {
+    templates: Vec<TemplateInfo<'a>>,
+    warnings: &'a [String],
+}
+
+#[derive(Serialize)]
+struct TemplateInfo<'a> {
+    id: &'a str,
+    name: &'a str,
+    aliases: Vec<&'a str>,
+    description: &'a str,
+    options: Vec<OptionInfo>,
 }
 
-fn list_templates() {
+#[derive(Serialize)]
+struct OptionInfo {
+    flag: &'static str,
+    values: Vec<&'static str>,
+}
+
+fn list_templates(format: OutputFormat) -> anyhow::Result<()> {
+    let registry = Registry::load();
+
+    if format == OutputFormat::Json {
+        let templates: Vec<TemplateInfo> = registry
+            .iter()
+            .map(|t| TemplateInfo {
+                id: t.id(),
+                name: t.display_name(),
+                aliases: t.aliases(),
+                description: t.description(),
+                options: t
+                    .features()
+                    .iter()
+                    .map(|f| OptionInfo { flag: f.flag(), values: f.values() })
+                    .collect(),
+            })
+            .collect();
+        let report = ListReport { templates, warnings: &registry.warnings };
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+
+    registry.warnings.iter().for_each(|w| warn(w));
     println!("\n{}", "📚 Available Templates:".bright_cyan().bold());
     println!();
     
-    let templates = vec![
-        ("Flask Portfolio", "Python Flask-based portfolio website with modern UI"),
-        ("MERN Stack", "MongoDB, Express, React, Node.js full-stack application"),
-        ("Next.js App", "Modern React framework with SSR and routing"),
-        ("React Portfolio", "Single-page portfolio with React and Tailwind CSS"),
-        ("Vue Portfolio", "Vue.js 3 portfolio with Composition API"),
-        ("Static HTML", "Pure HTML/CSS/JS portfolio template"),
-    ];
-
-    for (name, description) in templates {
-        println!("  {} {}", "•".bright_green(), name.bright_white().bold());
-        println!("    {}", description.dimmed());
+    for template in registry.iter() {
+        let mut names = vec![template.id()];
+        names.extend(template.aliases());
+        println!(
+            "  {} {} {}",
+            "•".bright_green(),
+            template.display_name().bright_white().bold(),
+            format!("({})", names.join(", ")).dimmed()
+        );
+        println!("    {}", template.description().dimmed());
         println!();
     }
+
+    Ok(())
+}
+
+fn warn(message: &str) {
+    eprintln!("{} {}", "warning:".yellow().bold(), message);
 }
 
 fn interactive_mode() -> anyhow::Result<()> {
     println!("\n{}", "🎨 Website Template Generator".bright_cyan().bold());
     println!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━".bright_cyan());
     
-    handle_new_project(None, None, None)?;
+    // Parse an empty command line so WEBGEN_* environment variables still apply.
+    let matches = NewArgs::augment_args(clap::Command::new("webgen")).get_matches_from(["webgen"]);
+    handle_new_project(NewArgs::from_arg_matches(&matches)?)?;
     
     Ok(())
 }
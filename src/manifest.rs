@@ -0,0 +1,91 @@
+//! `.webgen.json`, written into every generated project: which template,
+//! webgen version and options produced it, and a hash of each file as
+//! generated so that customised files can be told from untouched ones.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::generator::Options;
+use crate::plan::{PlannedFile, ProjectPlan};
+use crate::render::Variables;
+use crate::templates::Template;
+
+pub const FILE_NAME: &str = ".webgen.json";
+
+#[derive(Deserialize, Serialize)]
+pub struct Manifest {
+    pub webgen_version: String,
+    pub template: String,
+    pub name: String,
+    pub options: Options,
+    /// The values the files were rendered with that the options do not
+    /// determine: the copyright year. Everything else is derived again.
+    #[serde(default)]
+    pub variables: Variables,
+    /// SHA-256 of each generated file, by path.
+    pub files: BTreeMap<String, String>,
+    /// Generated contents of the files that `webgen upgrade` left differing
+    /// from the template, by path: the base of their next three-way merge,
+    /// which is in neither the project nor its git history.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub bases: BTreeMap<String, String>,
+}
+
+impl Manifest {
+    pub fn new(
+        template: &str,
+        name: &str,
+        options: &Options,
+        variables: &Variables,
+        files: &[PlannedFile],
+    ) -> Self {
+        let mut recorded = Variables::new();
+        if let Some(year) = variables.get("year") {
+            recorded.insert("year", year.clone());
+        }
+        Self {
+            webgen_version: crate::VERSION.to_string(),
+            template: template.to_string(),
+            name: name.to_string(),
+            options: options.clone(),
+            variables: recorded,
+            files: files.iter().map(|f| (f.path.clone(), f.sha256.clone())).collect(),
+            bases: BTreeMap::new(),
+        }
+    }
+
+    /// Reads the manifest of the project in `dir`.
+    pub fn load(dir: &Path) -> Result<Self> {
+        let path = dir.join(FILE_NAME);
+        let raw = fs::read_to_string(&path).with_context(|| {
+            format!("Failed to read {} (was the project generated by webgen?)", path.display())
+        })?;
+        serde_json::from_str(&raw).with_context(|| format!("Invalid manifest {}", path.display()))
+    }
+
+    pub fn to_json(&self) -> String {
+        let mut json = serde_json::to_string_pretty(self).expect("manifests serialize");
+        json.push('\n');
+        json
+    }
+
+    /// Renders the template again with the recorded options, as this version
+    /// of webgen would generate the project today.
+    pub fn regenerate(&self, template: &dyn Template) -> Result<ProjectPlan> {
+        let mut options = self.options.clone();
+        // The copyright year stays the one the project was generated in.
+        if let Some(year) = self.variables.get("year") {
+            options.vars.entry("year").or_insert_with(|| year.clone());
+        }
+        ProjectPlan::new(&self.name, template, &options)
+    }
+
+    /// Whether `contents` is what webgen generated at `path`.
+    pub fn is_untouched(&self, path: &str, contents: &[u8]) -> bool {
+        self.files.get(path).is_some_and(|hash| *hash == crate::plan::hash(contents))
+    }
+}
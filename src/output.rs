@@ -0,0 +1,199 @@
+//! Destinations for a rendered [`ProjectPlan`](crate::plan::ProjectPlan):
+//! a directory on disk, an in-memory map or a `.zip` / `.tar.gz` archive.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::io::{self, Seek, Write};
+use std::mem;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{bail, Result};
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+/// Permissions given to generated files and folders in archives.
+pub const FILE_MODE: u32 = 0o644;
+pub const DIR_MODE: u32 = 0o755;
+
+/// Somewhere the files of a project can be written to, one at a time.
+/// Paths are relative and `/`-separated.
+pub trait Output {
+    fn write(&mut self, path: &str, contents: &str) -> Result<()>;
+
+    /// Called once every file has been written.
+    fn finish(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Fails unless `path` is relative and stays below the folder it is written
+/// to: not absolute, and no empty, `.` or `..` component.
+pub fn check_path(path: &str) -> Result<()> {
+    let escapes = path.split(['/', '\\']).any(|part| matches!(part, "" | "." | ".."));
+    if escapes || Path::new(path).is_absolute() {
+        bail!("{:?} is not a path inside the project", path);
+    }
+    Ok(())
+}
+
+/// Writes below a directory, creating folders as needed.
+pub struct Directory {
+    root: PathBuf,
+}
+
+impl Directory {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+}
+
+impl Output for Directory {
+    fn write(&mut self, path: &str, contents: &str) -> Result<()> {
+        check_path(path)?;
+        let dest = self.root.join(path);
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&dest, contents)?;
+        Ok(())
+    }
+}
+
+/// Collects the files by path.
+impl Output for BTreeMap<String, String> {
+    fn write(&mut self, path: &str, contents: &str) -> Result<()> {
+        self.insert(path.to_string(), contents.to_string());
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArchiveFormat {
+    Zip,
+    TarGz,
+}
+
+impl ArchiveFormat {
+    /// The format matching `path`'s extension (`.zip`, `.tar.gz` or `.tgz`).
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let name = path.file_name()?.to_string_lossy().to_lowercase();
+        if name.ends_with(".zip") {
+            Some(Self::Zip)
+        } else if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            Some(Self::TarGz)
+        } else {
+            None
+        }
+    }
+}
+
+/// Streams the files into an archive, each below a single top-level folder
+/// (usually the project name) so that extracting it yields one directory.
+pub struct Archive<W: Write + Seek> {
+    prefix: String,
+    dirs: BTreeSet<String>,
+    state: State<W>,
+}
+
+enum State<W: Write + Seek> {
+    Zip(ZipWriter<W>),
+    TarGz(tar::Builder<GzEncoder<W>>),
+    Finished(W),
+    /// Only seen if finishing failed half-way.
+    Poisoned,
+}
+
+impl<W: Write + Seek> Archive<W> {
+    pub fn new(writer: W, format: ArchiveFormat, prefix: &str) -> Self {
+        let state = match format {
+            ArchiveFormat::Zip => State::Zip(ZipWriter::new(writer)),
+            ArchiveFormat::TarGz => {
+                State::TarGz(tar::Builder::new(GzEncoder::new(writer, Compression::default())))
+            }
+        };
+        Self {
+            prefix: prefix.trim_matches('/').to_string(),
+            dirs: BTreeSet::new(),
+            state,
+        }
+    }
+
+    /// Finishes the archive if needed and hands back the underlying writer.
+    pub fn into_inner(mut self) -> Result<W> {
+        self.finish()?;
+        match self.state {
+            State::Finished(writer) => Ok(writer),
+            _ => bail!("The archive could not be finished"),
+        }
+    }
+
+    /// Adds entries for the folders above `entry` that are not in the archive yet.
+    fn add_dirs(&mut self, entry: &str) -> Result<()> {
+        for (end, _) in entry.match_indices('/') {
+            let dir = &entry[..=end];
+            if !self.dirs.insert(dir.to_string()) {
+                continue;
+            }
+            match &mut self.state {
+                State::Zip(zip) => {
+                    zip.add_directory(dir, SimpleFileOptions::default().unix_permissions(DIR_MODE))?
+                }
+                State::TarGz(tar) => {
+                    let mut header = header(tar::EntryType::Directory, DIR_MODE, 0);
+                    tar.append_data(&mut header, dir, io::empty())?;
+                }
+                State::Finished(_) | State::Poisoned => bail!("The archive is already finished"),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<W: Write + Seek> Output for Archive<W> {
+    fn write(&mut self, path: &str, contents: &str) -> Result<()> {
+        check_path(path)?;
+        let entry = if self.prefix.is_empty() {
+            path.to_string()
+        } else {
+            format!("{}/{}", self.prefix, path)
+        };
+        self.add_dirs(&entry)?;
+        match &mut self.state {
+            State::Zip(zip) => {
+                let options = SimpleFileOptions::default()
+                    .compression_method(CompressionMethod::Deflated)
+                    .unix_permissions(FILE_MODE);
+                zip.start_file(entry, options)?;
+                zip.write_all(contents.as_bytes())?;
+            }
+            State::TarGz(tar) => {
+                let mut header = header(tar::EntryType::Regular, FILE_MODE, contents.len() as u64);
+                tar.append_data(&mut header, entry, contents.as_bytes())?;
+            }
+            State::Finished(_) | State::Poisoned => bail!("The archive is already finished"),
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<()> {
+        self.state = match mem::replace(&mut self.state, State::Poisoned) {
+            State::Zip(zip) => State::Finished(zip.finish()?),
+            State::TarGz(tar) => State::Finished(tar.into_inner()?.finish()?),
+            finished => finished,
+        };
+        Ok(())
+    }
+}
+
+fn header(kind: tar::EntryType, mode: u32, size: u64) -> tar::Header {
+    let mut header = tar::Header::new_gnu();
+    header.set_entry_type(kind);
+    header.set_mode(mode);
+    header.set_size(size);
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or_default();
+    header.set_mtime(now);
+    header
+}
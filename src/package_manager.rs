@@ -0,0 +1,149 @@
+//! The JavaScript package manager a project uses (`--package-manager`), and
+//! the commands, lockfiles and CI/Docker setup that go with it.
+
+use std::env;
+use std::path::Path;
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PackageManager {
+    #[default]
+    Npm,
+    Pnpm,
+    Yarn,
+    Bun,
+}
+
+impl PackageManager {
+    const ALL: [PackageManager; 4] = [Self::Npm, Self::Pnpm, Self::Yarn, Self::Bun];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Npm => "npm",
+            Self::Pnpm => "pnpm",
+            Self::Yarn => "yarn",
+            Self::Bun => "bun",
+        }
+    }
+
+    pub fn install_cmd(self) -> &'static str {
+        match self {
+            Self::Npm => "npm install",
+            Self::Pnpm => "pnpm install",
+            Self::Yarn => "yarn install",
+            Self::Bun => "bun install",
+        }
+    }
+
+    /// Prefix for running a package.json script: `{run_cmd} build`.
+    pub fn run_cmd(self) -> &'static str {
+        match self {
+            Self::Npm => "npm run",
+            Self::Pnpm => "pnpm",
+            Self::Yarn => "yarn",
+            Self::Bun => "bun run",
+        }
+    }
+
+    pub fn start_cmd(self) -> &'static str {
+        match self {
+            Self::Npm => "npm start",
+            Self::Pnpm => "pnpm start",
+            Self::Yarn => "yarn start",
+            Self::Bun => "bun run start",
+        }
+    }
+
+    /// Value for package.json's `packageManager` field. npm is left out: it is
+    /// what Node ships with and Corepack does not manage it by default.
+    pub fn spec(self) -> Option<&'static str> {
+        match self {
+            Self::Npm => None,
+            Self::Pnpm => Some("pnpm@9.12.0"),
+            Self::Yarn => Some("yarn@4.5.0"),
+            Self::Bun => Some("bun@1.1.30"),
+        }
+    }
+
+    /// Dockerfile lines that install dependencies in a stock `node` image,
+    /// copying the manifests first so the layer is cached.
+    pub fn docker_install(self) -> &'static str {
+        match self {
+            Self::Npm => "COPY package.json package-lock.json* ./\nRUN npm install",
+            Self::Pnpm => {
+                "ENV COREPACK_ENABLE_DOWNLOAD_PROMPT=0\nRUN corepack enable\n\
+                 COPY package.json pnpm-lock.yaml* ./\nRUN pnpm install"
+            }
+            Self::Yarn => {
+                "ENV COREPACK_ENABLE_DOWNLOAD_PROMPT=0\nRUN corepack enable\n\
+                 COPY package.json yarn.lock* .yarnrc.yml* ./\nRUN yarn install"
+            }
+            Self::Bun => "RUN npm install -g bun\nCOPY package.json bun.lock* ./\nRUN bun install",
+        }
+    }
+
+    /// GitHub Actions step that puts the manager on PATH ahead of
+    /// `actions/setup-node`, which only brings npm.
+    pub fn github_setup(self) -> Option<&'static str> {
+        match self {
+            Self::Npm => None,
+            Self::Pnpm => Some("uses: pnpm/action-setup@v4"),
+            Self::Yarn => Some("run: corepack enable"),
+            Self::Bun => Some("uses: oven-sh/setup-bun@v2"),
+        }
+    }
+
+    /// `actions/setup-node`'s `cache` input; it has no bun support. setup-node
+    /// fails when the lockfile is missing, as it is until the first install is
+    /// committed, so the cache is only turned on once the lockfile exists.
+    pub fn github_cache(self) -> Option<String> {
+        match self {
+            Self::Bun => None,
+            pm => Some(format!(
+                "${{{{ hashFiles('{}') != '' && '{}' || '' }}}}",
+                pm.lockfile(),
+                pm.as_str()
+            )),
+        }
+    }
+
+    /// Shell command that makes the manager available in a stock `node` image.
+    pub fn setup_cmd(self) -> Option<&'static str> {
+        match self {
+            Self::Npm => None,
+            Self::Pnpm | Self::Yarn => Some("corepack enable"),
+            Self::Bun => Some("npm install -g bun"),
+        }
+    }
+
+    pub fn lockfile(self) -> &'static str {
+        self.lockfiles()[0]
+    }
+
+    fn lockfiles(self) -> &'static [&'static str] {
+        match self {
+            Self::Npm => &["package-lock.json"],
+            Self::Pnpm => &["pnpm-lock.yaml"],
+            Self::Yarn => &["yarn.lock"],
+            Self::Bun => &["bun.lock", "bun.lockb"],
+        }
+    }
+
+    /// The manager webgen was launched through (`pnpm dlx`, `bunx`, ...), else
+    /// the one whose lockfile is already in `dir`.
+    pub fn detect(dir: &Path) -> Option<Self> {
+        let launched_by = env::var("npm_config_user_agent").ok().and_then(|agent| {
+            let name = agent.split('/').next()?.to_string();
+            Self::ALL.into_iter().find(|pm| pm.as_str() == name)
+        });
+        let locked = || {
+            Self::ALL
+                .into_iter()
+                .find(|pm| pm.lockfiles().iter().any(|lock| dir.join(lock).is_file()))
+        };
+        launched_by.or_else(locked)
+    }
+}
@@ -0,0 +1,152 @@
+use std::collections::BTreeMap;
+
+use anyhow::{Context, Result};
+use colored::*;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::generator::Options;
+use crate::manifest::{self, Manifest};
+use crate::output::{self, Output};
+use crate::render::{self, Variables};
+use crate::templates::Template;
+
+/// Everything a template will produce, rendered but not yet written.
+#[derive(Serialize)]
+pub struct ProjectPlan {
+    pub name: String,
+    pub template: String,
+    pub files: Vec<PlannedFile>,
+}
+
+#[derive(Serialize)]
+pub struct PlannedFile {
+    pub path: String,
+    #[serde(skip)]
+    pub contents: String,
+    pub size: usize,
+    pub sha256: String,
+}
+
+impl PlannedFile {
+    pub fn new(path: String, contents: String) -> Self {
+        Self {
+            size: contents.len(),
+            sha256: hash(contents.as_bytes()),
+            path,
+            contents,
+        }
+    }
+}
+
+impl ProjectPlan {
+    /// Plans `template` with `options`, the way `webgen new` does, including
+    /// the project's [`Manifest`].
+    pub fn new(name: &str, template: &dyn Template, options: &Options) -> Result<Self> {
+        options.check(template)?;
+        let vars = options.variables(name, template);
+        let mut plan = Self::build(name, template, &vars)?;
+        let manifest = Manifest::new(template.id(), name, options, &vars, &plan.files);
+        plan.files.push(PlannedFile::new(manifest::FILE_NAME.to_string(), manifest.to_json()));
+        Ok(plan)
+    }
+
+    pub fn build(name: &str, template: &dyn Template, vars: &Variables) -> Result<Self> {
+        let mut files = Vec::new();
+        for file in template.files(vars) {
+            let path = render::render(&file.path, vars)
+                .with_context(|| format!("Failed to render file name {}", file.path))?;
+            output::check_path(&path)
+                .with_context(|| format!("Invalid file name rendered from {}", file.path))?;
+            let contents = render::render(&file.source, vars)
+                .with_context(|| format!("Failed to render {}", path))?;
+            files.push(PlannedFile::new(path, contents));
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+            template: template.id().to_string(),
+            files,
+        })
+    }
+
+    /// Writes every file to `out`, then finishes it.
+    pub fn render(&self, out: &mut dyn Output) -> Result<()> {
+        for file in &self.files {
+            out.write(&file.path, &file.contents)
+                .with_context(|| format!("Failed to write {}", file.path))?;
+        }
+        out.finish()
+    }
+
+    pub fn total_size(&self) -> usize {
+        self.files.iter().map(|f| f.size).sum()
+    }
+
+    pub fn print_tree(&self) {
+        let mut root = Dir::default();
+        for file in &self.files {
+            let mut dir = &mut root;
+            let mut parts: Vec<&str> = file.path.split('/').collect();
+            let leaf = parts.pop().unwrap_or_default();
+            for part in parts {
+                dir = dir.dirs.entry(part.to_string()).or_default();
+            }
+            dir.files.insert(leaf.to_string(), file);
+        }
+
+        println!("{}/", self.name.bright_white().bold());
+        root.print("");
+        println!(
+            "\n{} files, {}",
+            self.files.len(),
+            human_size(self.total_size())
+        );
+    }
+}
+
+#[derive(Default)]
+struct Dir<'a> {
+    dirs: BTreeMap<String, Dir<'a>>,
+    files: BTreeMap<String, &'a PlannedFile>,
+}
+
+impl Dir<'_> {
+    fn print(&self, prefix: &str) {
+        let count = self.dirs.len() + self.files.len();
+        let entries = self
+            .dirs
+            .iter()
+            .map(|(name, dir)| (name, Some(dir), None))
+            .chain(self.files.iter().map(|(name, file)| (name, None, Some(*file))));
+
+        for (i, (name, dir, file)) in entries.enumerate() {
+            let last = i + 1 == count;
+            let branch = if last { "└── " } else { "├── " };
+            if let Some(dir) = dir {
+                println!("{}{}{}/", prefix, branch, name.bright_cyan());
+                dir.print(&format!("{}{}", prefix, if last { "    " } else { "│   " }));
+            } else if let Some(file) = file {
+                println!(
+                    "{}{}{}  {}",
+                    prefix,
+                    branch,
+                    name,
+                    format!("{}  {}", human_size(file.size), &file.sha256[..12]).dimmed()
+                );
+            }
+        }
+    }
+}
+
+pub fn hash(contents: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(contents))
+}
+
+fn human_size(bytes: usize) -> String {
+    if bytes < 1024 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} KB", bytes as f64 / 1024.0)
+    }
+}
@@ -0,0 +1,160 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::render::Variables;
+
+/// Personal details rendered into the portfolio templates' hero, about,
+/// experience, projects and contact sections.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Profile {
+    pub name: Option<String>,
+    pub title: Option<String>,
+    pub bio: Option<String>,
+    pub email: Option<String>,
+    #[serde(default)]
+    pub skills: Vec<Skill>,
+    #[serde(default)]
+    pub work: Vec<Work>,
+    #[serde(default)]
+    pub projects: Vec<Project>,
+    #[serde(default)]
+    pub social: Vec<Social>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Skill {
+    pub name: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub keywords: Vec<String>,
+    pub icon: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Work {
+    pub company: String,
+    #[serde(default)]
+    pub position: String,
+    /// `YYYY`, `YYYY-MM` or `YYYY-MM-DD`; anything else is shown as written.
+    pub start: Option<String>,
+    /// Left out for the current position.
+    pub end: Option<String>,
+    pub summary: Option<String>,
+    #[serde(default)]
+    pub highlights: Vec<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Project {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default, alias = "keywords")]
+    pub tech: Vec<String>,
+    #[serde(default)]
+    pub url: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Social {
+    pub network: String,
+    pub url: String,
+}
+
+impl Profile {
+    pub fn load(path: &Path) -> Result<Self> {
+        let raw = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let extension = path.extension().and_then(|e| e.to_str()).unwrap_or_default();
+        let profile = match extension.to_lowercase().as_str() {
+            "yaml" | "yml" => serde_yaml::from_str(&raw).map_err(anyhow::Error::from),
+            "json" => serde_json::from_str(&raw).map_err(anyhow::Error::from),
+            "toml" => toml::from_str(&raw).map_err(anyhow::Error::from),
+            _ => bail!(
+                "Unsupported profile format '{}': use .yaml, .json or .toml",
+                path.display()
+            ),
+        };
+        profile.with_context(|| format!("Invalid profile {}", path.display()))
+    }
+
+    /// Overlays the fields this profile sets on top of the template's default
+    /// `profile` variable. The name is used as the author (see `project_variables`).
+    pub fn apply(&self, vars: &mut Variables) {
+        let mut fields = Map::new();
+        for (key, value) in [("title", &self.title), ("bio", &self.bio), ("email", &self.email)] {
+            if let Some(value) = value {
+                fields.insert(key.to_string(), Value::from(value.as_str()));
+            }
+        }
+        if !self.skills.is_empty() {
+            let skills = self.skills.iter().map(|skill| {
+                let description = skill
+                    .description
+                    .clone()
+                    .unwrap_or_else(|| skill.keywords.join(", "));
+                serde_json::json!({
+                    "name": skill.name,
+                    "description": description,
+                    "icon": skill.icon,
+                })
+            });
+            fields.insert("skills".to_string(), skills.collect());
+        }
+        if !self.work.is_empty() {
+            let work = self.work.iter().map(|job| {
+                serde_json::json!({
+                    "company": job.company,
+                    "position": job.position,
+                    "period": period(job.start.as_deref(), job.end.as_deref()),
+                    "summary": job.summary,
+                    "highlights": job.highlights,
+                })
+            });
+            fields.insert("work".to_string(), work.collect());
+        }
+        if !self.projects.is_empty() {
+            fields.insert("projects".to_string(), serde_json::json!(self.projects));
+        }
+        if !self.social.is_empty() {
+            fields.insert("social".to_string(), serde_json::json!(self.social));
+        }
+
+        vars.merge("profile", fields);
+        let contact = vars.flag("profile.email") || vars.flag("profile.social");
+        vars.merge("profile", Map::from_iter([("contact".to_string(), Value::from(contact))]));
+    }
+}
+
+/// "Jan 2020 – Present", "2018 – Mar 2020", ...
+fn period(start: Option<&str>, end: Option<&str>) -> String {
+    match (start, end) {
+        (Some(start), Some(end)) => format!("{} – {}", month_year(start), month_year(end)),
+        (Some(start), None) => format!("{} – Present", month_year(start)),
+        (None, Some(end)) => month_year(end),
+        (None, None) => String::new(),
+    }
+}
+
+fn month_year(date: &str) -> String {
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+    let mut parts = date.trim().split('-');
+    let year = parts.next().filter(|y| y.len() == 4 && y.bytes().all(|b| b.is_ascii_digit()));
+    let month = parts.next().map(|m| m.parse::<usize>().ok());
+    match (year, month) {
+        (Some(year), None) => year.to_string(),
+        (Some(year), Some(Some(month @ 1..=12))) => format!("{} {}", MONTHS[month - 1], year),
+        _ => date.to_string(),
+    }
+}
@@ -0,0 +1,448 @@
+// Minimal Handlebars-style renderer applied to every template file.
+//
+//   {{name}}  {{profile.name}}  {{name | slug}}  {{name | quote}}
+//   {{#if flag}} ... {{else}} ... {{/if}}     {{#unless flag}} ... {{/unless}}
+//   {{#each items}} {{this}} {{@index}} {{#unless @last}},{{/unless}} {{/each}}
+//   {{#raw}} ... {{/raw}}
+//
+// Expressions that don't resolve to a variable are emitted untouched, so
+// Jinja, Vue and GitHub Actions `{{ ... }}` syntax passes straight through.
+
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct Variables {
+    values: Map<String, Value>,
+}
+
+impl Variables {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, key: &str, value: impl Into<Value>) {
+        self.values.insert(key.to_string(), value.into());
+    }
+
+    pub fn get(&self, path: &str) -> Option<&Value> {
+        let mut segments = path.split('.');
+        let first = self.values.get(segments.next()?)?;
+        segments.try_fold(first, |value, key| value.get(key))
+    }
+
+    /// The string at `path`, or "" when it is missing or not a string.
+    pub fn text(&self, path: &str) -> &str {
+        self.get(path).and_then(Value::as_str).unwrap_or_default()
+    }
+
+    pub fn flag(&self, key: &str) -> bool {
+        self.get(key).is_some_and(truthy)
+    }
+
+    pub fn insert_default(&mut self, key: &str, value: impl Into<Value>) {
+        self.values.entry(key).or_insert_with(|| value.into());
+    }
+
+    /// Sets `fields` on the object stored at `key`, keeping its other fields.
+    pub fn merge(&mut self, key: &str, fields: Map<String, Value>) {
+        let entry = self.values.entry(key).or_insert_with(|| Value::Object(Map::new()));
+        match entry {
+            Value::Object(map) => map.extend(fields),
+            other => *other = Value::Object(fields),
+        }
+    }
+}
+
+pub fn render(source: &str, vars: &Variables) -> Result<String> {
+    let nodes = Parser::new(source).parse()?;
+    let root = Scope { item: Value::Object(vars.values.clone()), index: 0, len: 1 };
+    let mut out = String::with_capacity(source.len());
+    render_nodes(&nodes, &mut vec![root], &mut out)?;
+    Ok(out)
+}
+
+enum Node<'a> {
+    Text(&'a str),
+    Var { raw: &'a str, path: &'a str, filters: Vec<&'a str> },
+    If { path: &'a str, negate: bool, then: Vec<Node<'a>>, otherwise: Vec<Node<'a>> },
+    Each { path: &'a str, body: Vec<Node<'a>> },
+}
+
+enum Tag<'a> {
+    Open { kind: &'a str, path: &'a str },
+    Else,
+    Close(&'a str),
+    Raw,
+    Expr { path: &'a str, filters: Vec<&'a str> },
+    Verbatim,
+}
+
+struct Parser<'a> {
+    src: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(src: &'a str) -> Self {
+        Self { src, pos: 0 }
+    }
+
+    fn parse(mut self) -> Result<Vec<Node<'a>>> {
+        Ok(self.parse_block(None)?.0)
+    }
+
+    // Parses until the closing tag of `open` (or its `else`) and reports which one stopped it.
+    fn parse_block(&mut self, open: Option<&str>) -> Result<(Vec<Node<'a>>, Option<&'a str>)> {
+        let mut nodes = Vec::new();
+
+        while let Some(offset) = self.src[self.pos..].find("{{") {
+            let start = self.pos + offset;
+            let Some(close) = self.src[start..].find("}}") else {
+                break;
+            };
+            let end = start + close + 2;
+            let inner = self.src[start + 2..end - 2].trim();
+            let tag = classify(inner);
+
+            if matches!(tag, Tag::Expr { .. } | Tag::Verbatim) {
+                nodes.push(Node::Text(&self.src[self.pos..start]));
+                if let Tag::Expr { path, filters } = tag {
+                    nodes.push(Node::Var { raw: &self.src[start..end], path, filters });
+                } else {
+                    nodes.push(Node::Text(&self.src[start..end]));
+                }
+                self.pos = end;
+                continue;
+            }
+
+            let (text_end, next) = self.standalone(start, end);
+            nodes.push(Node::Text(&self.src[self.pos..text_end]));
+            self.pos = next;
+
+            match tag {
+                Tag::Raw => {
+                    let Some(raw_end) = self.src[self.pos..].find("{{/raw}}") else {
+                        bail!("unclosed {{{{#raw}}}} on line {}", self.line(start));
+                    };
+                    let body_end = self.pos + raw_end;
+                    let (text_end, next) = self.standalone(body_end, body_end + "{{/raw}}".len());
+                    nodes.push(Node::Text(&self.src[self.pos..text_end]));
+                    self.pos = next;
+                }
+                Tag::Open { kind, path } => {
+                    let line = self.line(start);
+                    let (then, stop) = self.parse_block(Some(kind))?;
+                    let (otherwise, stop) = if stop == Some("else") {
+                        if kind == "each" {
+                            bail!("{{{{else}}}} is not supported inside {{{{#each}}}} (line {})", line);
+                        }
+                        self.parse_block(Some(kind))?
+                    } else {
+                        (Vec::new(), stop)
+                    };
+                    if stop != Some(kind) {
+                        bail!("unclosed {{{{#{} {}}}}} opened on line {}", kind, path, line);
+                    }
+                    nodes.push(match kind {
+                        "each" => Node::Each { path, body: then },
+                        _ => Node::If { path, negate: kind == "unless", then, otherwise },
+                    });
+                }
+                Tag::Else => {
+                    if open.is_none() {
+                        bail!("{{{{else}}}} outside of a block on line {}", self.line(start));
+                    }
+                    return Ok((nodes, Some("else")));
+                }
+                Tag::Close(kind) => {
+                    if open != Some(kind) {
+                        bail!("unexpected {{{{/{}}}}} on line {}", kind, self.line(start));
+                    }
+                    return Ok((nodes, Some(kind)));
+                }
+                Tag::Expr { .. } | Tag::Verbatim => unreachable!(),
+            }
+        }
+
+        nodes.push(Node::Text(&self.src[self.pos..]));
+        self.pos = self.src.len();
+        Ok((nodes, None))
+    }
+
+    // A block tag alone on its line swallows the line's indentation and newline,
+    // so `{{#if}}`/`{{/if}}` lines don't leave blank lines behind.
+    fn standalone(&self, start: usize, end: usize) -> (usize, usize) {
+        let line_start = self.src[..start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = self.src[end..].find('\n').map_or(self.src.len(), |i| end + i + 1);
+
+        if line_start >= self.pos
+            && self.src[line_start..start].trim().is_empty()
+            && self.src[end..line_end].trim().is_empty()
+        {
+            (line_start, line_end)
+        } else {
+            (start, end)
+        }
+    }
+
+    fn line(&self, at: usize) -> usize {
+        self.src[..at].matches('\n').count() + 1
+    }
+}
+
+fn classify(inner: &str) -> Tag<'_> {
+    if let Some(rest) = inner.strip_prefix('#') {
+        let (kind, path) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+        let path = path.trim();
+        return match kind {
+            "raw" if path.is_empty() => Tag::Raw,
+            "if" | "unless" | "each" if is_path(path) => Tag::Open { kind, path },
+            _ => Tag::Verbatim,
+        };
+    }
+    if let Some(kind) = inner.strip_prefix('/') {
+        return match kind.trim() {
+            "if" => Tag::Close("if"),
+            "unless" => Tag::Close("unless"),
+            "each" => Tag::Close("each"),
+            _ => Tag::Verbatim,
+        };
+    }
+    if inner == "else" {
+        return Tag::Else;
+    }
+
+    let mut parts = inner.split('|').map(str::trim);
+    let path = parts.next().unwrap_or_default();
+    let filters: Vec<&str> = parts.collect();
+    if is_path(path) && filters.iter().all(|f| is_ident(f)) {
+        Tag::Expr { path, filters }
+    } else {
+        Tag::Verbatim
+    }
+}
+
+fn is_path(s: &str) -> bool {
+    !s.is_empty() && s.split('.').all(|segment| is_ident(segment.strip_prefix('@').unwrap_or(segment)))
+}
+
+fn is_ident(s: &str) -> bool {
+    let mut chars = s.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+struct Scope {
+    item: Value,
+    index: usize,
+    len: usize,
+}
+
+fn lookup(scopes: &[Scope], path: &str) -> Option<Value> {
+    let inner = scopes.last()?;
+    match path {
+        "@index" => return Some(Value::from(inner.index)),
+        "@first" => return Some(Value::from(inner.index == 0)),
+        "@last" => return Some(Value::from(inner.index + 1 == inner.len)),
+        _ => {}
+    }
+
+    let mut segments = path.split('.');
+    let first = segments.next()?;
+    let start = if first == "this" {
+        &inner.item
+    } else {
+        scopes.iter().rev().find_map(|scope| scope.item.get(first))?
+    };
+    segments.try_fold(start, |value, key| value.get(key)).cloned()
+}
+
+fn render_nodes(nodes: &[Node], scopes: &mut Vec<Scope>, out: &mut String) -> Result<()> {
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(text),
+            Node::Var { raw, path, filters } => match lookup(scopes, path) {
+                Some(mut value) => {
+                    for filter in filters {
+                        value = apply_filter(filter, value)?;
+                    }
+                    out.push_str(&to_text(&value));
+                }
+                None => out.push_str(raw),
+            },
+            Node::If { path, negate, then, otherwise } => {
+                let truth = lookup(scopes, path).is_some_and(|v| truthy(&v));
+                let branch = if truth != *negate { then } else { otherwise };
+                render_nodes(branch, scopes, out)?;
+            }
+            Node::Each { path, body } => {
+                let items = match lookup(scopes, path) {
+                    None | Some(Value::Null) => continue,
+                    Some(Value::Array(items)) => items,
+                    Some(_) => bail!("{{{{#each {}}}}} expects a list", path),
+                };
+                let len = items.len();
+                for (index, item) in items.into_iter().enumerate() {
+                    scopes.push(Scope { item, index, len });
+                    let result = render_nodes(body, scopes, out);
+                    scopes.pop();
+                    result?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn truthy(value: &Value) -> bool {
+    match value {
+        Value::Null => false,
+        Value::Bool(b) => *b,
+        Value::Number(n) => n.as_f64() != Some(0.0),
+        Value::String(s) => !s.is_empty(),
+        Value::Array(items) => !items.is_empty(),
+        Value::Object(map) => !map.is_empty(),
+    }
+}
+
+fn to_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        Value::Array(items) => items.iter().map(to_text).collect::<Vec<_>>().join(", "),
+        other => other.to_string(),
+    }
+}
+
+fn apply_filter(filter: &str, value: Value) -> Result<Value> {
+    let text = to_text(&value);
+    Ok(Value::String(match filter {
+        "json" => value.to_string(),
+        // Single-quoted string literal, valid in both JavaScript and Python.
+        "quote" => format!(
+            "'{}'",
+            text.replace('\\', "\\\\")
+                .replace('\'', "\\'")
+                .replace('\n', "\\n")
+                .replace('\r', "\\r")
+        ),
+        "slug" => slugify(&text),
+        "upper" => text.to_uppercase(),
+        "lower" => text.to_lowercase(),
+        "html" => text
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+            .replace('\'', "&#39;"),
+        _ => bail!("unknown filter '{}'", filter),
+    }))
+}
+
+pub fn slugify(text: &str) -> String {
+    let mut slug = String::with_capacity(text.len());
+    for c in text.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.trim_end_matches('-').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn vars(value: Value) -> Variables {
+        let Value::Object(values) = value else { panic!("expected an object") };
+        Variables { values }
+    }
+
+    fn error(source: &str) -> String {
+        render(source, &Variables::new()).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn standalone_block_lines_leave_no_blank_lines() {
+        let source = "a\n  {{#if on}}\n  b\n  {{else}}\n  c\n  {{/if}}\nd\n";
+        assert_eq!(render(source, &vars(json!({ "on": true }))).unwrap(), "a\n  b\nd\n");
+        assert_eq!(render(source, &vars(json!({ "on": false }))).unwrap(), "a\n  c\nd\n");
+    }
+
+    #[test]
+    fn inline_blocks_keep_the_surrounding_text() {
+        let source = "x {{#if on}}y{{/if}} z\n";
+        assert_eq!(render(source, &vars(json!({ "on": true }))).unwrap(), "x y z\n");
+    }
+
+    #[test]
+    fn each_exposes_the_item_and_loop_variables() {
+        let source = "{{#each items}}{{@index}}:{{this}}{{#unless @last}}, {{/unless}}{{/each}}";
+        let out = render(source, &vars(json!({ "items": ["a", "b"] }))).unwrap();
+        assert_eq!(out, "0:a, 1:b");
+    }
+
+    #[test]
+    fn else_inside_each_is_an_error() {
+        let message = error("\n{{#each items}}a{{else}}b{{/each}}");
+        assert_eq!(message, "{{else}} is not supported inside {{#each}} (line 2)");
+    }
+
+    #[test]
+    fn unclosed_blocks_report_the_line_they_open_on() {
+        assert_eq!(error("a\n\n{{#if on}}\nb\n"), "unclosed {{#if on}} opened on line 3");
+        assert_eq!(error("a\n{{#raw}}\nb\n"), "unclosed {{#raw}} on line 2");
+    }
+
+    #[test]
+    fn mismatched_blocks_report_the_closing_line() {
+        assert_eq!(error("{{#if on}}\na\n{{/each}}\n"), "unexpected {{/each}} on line 3");
+        assert_eq!(error("a\n{{/if}}"), "unexpected {{/if}} on line 2");
+        assert_eq!(error("a\n\n{{else}}"), "{{else}} outside of a block on line 3");
+    }
+
+    #[test]
+    fn unknown_expressions_pass_through() {
+        let source = "{{ missing }} {{ url_for('static') }} {{ $store.count }} {{ secrets.TOKEN }}";
+        assert_eq!(render(source, &Variables::new()).unwrap(), source);
+    }
+
+    #[test]
+    fn raw_blocks_are_not_rendered() {
+        let source = "{{#raw}}{{name}}{{/raw}} {{name}}";
+        assert_eq!(render(source, &vars(json!({ "name": "x" }))).unwrap(), "{{name}} x");
+    }
+
+    #[test]
+    fn filters() {
+        let vars = vars(json!({
+            "text": "It's a \"Test\" <b>&</b>\\ {x}\r\n",
+            "name": "My Cool_Site!",
+            "list": ["a", 1],
+        }));
+        let filter = |f: &str, path: &str| render(&format!("{{{{{} | {}}}}}", path, f), &vars).unwrap();
+
+        assert_eq!(filter("quote", "text"), r#"'It\'s a "Test" <b>&</b>\\ {x}\r\n'"#);
+        assert_eq!(filter("json", "text"), r#""It's a \"Test\" <b>&</b>\\ {x}\r\n""#);
+        assert_eq!(filter("json", "list"), r#"["a",1]"#);
+        assert_eq!(
+            filter("html", "text"),
+            "It&#39;s a &quot;Test&quot; &lt;b&gt;&amp;&lt;/b&gt;\\ {x}\r\n"
+        );
+        assert_eq!(filter("slug", "name"), "my-cool-site");
+        assert_eq!(filter("upper", "name"), "MY COOL_SITE!");
+        assert_eq!(filter("lower", "name"), "my cool_site!");
+        assert_eq!(render("{{name | slug | upper}}", &vars).unwrap(), "MY-COOL-SITE");
+        assert_eq!(
+            render("{{name | nope}}", &vars).unwrap_err().to_string(),
+            "unknown filter 'nope'"
+        );
+    }
+}
@@ -0,0 +1,137 @@
+//! Reads a JSON Resume (https://jsonresume.org/schema) into a [`Profile`].
+//!
+//! Only the sections the portfolio templates can show are read; everything
+//! else in the file (education, awards, ...) is ignored.
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::profile::{self, Profile, Social};
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Resume {
+    basics: Basics,
+    work: Vec<Work>,
+    projects: Vec<Project>,
+    skills: Vec<Skill>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Basics {
+    name: Option<String>,
+    label: Option<String>,
+    email: Option<String>,
+    summary: Option<String>,
+    profiles: Vec<SocialProfile>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct SocialProfile {
+    network: String,
+    username: Option<String>,
+    url: Option<String>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct Work {
+    // `company` is what the schema called the employer before v1.0.
+    #[serde(alias = "company")]
+    name: String,
+    position: String,
+    start_date: Option<String>,
+    end_date: Option<String>,
+    summary: Option<String>,
+    highlights: Vec<String>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Project {
+    name: String,
+    description: Option<String>,
+    highlights: Vec<String>,
+    keywords: Vec<String>,
+    url: Option<String>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Skill {
+    name: String,
+    keywords: Vec<String>,
+}
+
+pub fn load(path: &Path) -> Result<Profile> {
+    let raw = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let resume: Resume = serde_json::from_str(&raw)
+        .with_context(|| format!("Invalid JSON Resume {}", path.display()))?;
+    Ok(resume.into())
+}
+
+impl From<Resume> for Profile {
+    fn from(resume: Resume) -> Self {
+        let Resume { basics, work, projects, skills } = resume;
+
+        let social = basics.profiles.into_iter().filter_map(|p| {
+            let url = p.url.filter(|url| !url.is_empty()).or_else(|| {
+                let username = p.username.filter(|name| !name.is_empty())?;
+                profile_url(&p.network, &username)
+            })?;
+            Some(Social { network: p.network, url })
+        });
+        let work = work.into_iter().map(|job| profile::Work {
+            company: job.name,
+            position: job.position,
+            start: job.start_date,
+            end: job.end_date,
+            summary: job.summary,
+            highlights: job.highlights,
+        });
+        let projects = projects.into_iter().map(|project| profile::Project {
+            description: project
+                .description
+                .or_else(|| project.highlights.into_iter().next())
+                .unwrap_or_default(),
+            name: project.name,
+            tech: project.keywords,
+            url: project.url.unwrap_or_default(),
+        });
+        let skills = skills.into_iter().map(|skill| profile::Skill {
+            name: skill.name,
+            description: None,
+            keywords: skill.keywords,
+            icon: None,
+        });
+
+        Profile {
+            name: basics.name,
+            title: basics.label,
+            bio: basics.summary,
+            email: basics.email,
+            skills: skills.collect(),
+            work: work.collect(),
+            projects: projects.collect(),
+            social: social.collect(),
+        }
+    }
+}
+
+/// JSON Resume profiles often carry only a username for the well-known networks.
+fn profile_url(network: &str, username: &str) -> Option<String> {
+    let base = match network.to_lowercase().as_str() {
+        "github" => "https://github.com/",
+        "gitlab" => "https://gitlab.com/",
+        "linkedin" => "https://www.linkedin.com/in/",
+        "twitter" | "x" => "https://x.com/",
+        _ => return None,
+    };
+    Some(format!("{}{}", base, username))
+}
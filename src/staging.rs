@@ -0,0 +1,100 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, Once};
+
+use anyhow::{Context, Result};
+
+// Staging directories that still need removing if the user hits Ctrl-C, and
+// whether files are already being moved out of them.
+static ACTIVE: Mutex<Vec<(PathBuf, bool)>> = Mutex::new(Vec::new());
+static HANDLER: Once = Once::new();
+
+/// A hidden directory next to the final project that files are written into
+/// first. It is renamed into place by `commit` and removed on drop otherwise.
+pub struct Staging {
+    path: PathBuf,
+    committed: bool,
+}
+
+impl Staging {
+    pub fn new(output_dir: &Path, name: &str) -> Result<Self> {
+        let prefix = format!(".{}.webgen-", name);
+        remove_stale(output_dir, &prefix);
+
+        let path = output_dir.join(format!("{}{}", prefix, std::process::id()));
+        fs::create_dir_all(&path)
+            .with_context(|| format!("Failed to create staging directory {}", path.display()))?;
+        ACTIVE.lock().unwrap().push((path.clone(), false));
+
+        Ok(Self { path, committed: false })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Marks the files as being moved out one at a time (when merging into an
+    /// existing directory), so an interruption may leave some of them in place.
+    pub fn moving_out(&self) {
+        for (path, moving) in ACTIVE.lock().unwrap().iter_mut() {
+            if *path == self.path {
+                *moving = true;
+            }
+        }
+    }
+
+    pub fn commit(mut self, target: &Path) -> Result<()> {
+        fs::rename(&self.path, target)
+            .with_context(|| format!("Failed to move project into {}", target.display()))?;
+        self.committed = true;
+        Ok(())
+    }
+}
+
+impl Drop for Staging {
+    fn drop(&mut self) {
+        if !self.committed {
+            let _ = fs::remove_dir_all(&self.path);
+        }
+        ACTIVE.lock().unwrap().retain(|(p, _)| p != &self.path);
+    }
+}
+
+// Leftovers from a run that was killed before it could clean up.
+fn remove_stale(output_dir: &Path, prefix: &str) {
+    let Ok(entries) = fs::read_dir(output_dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        if entry.file_name().to_string_lossy().starts_with(prefix) {
+            let _ = fs::remove_dir_all(entry.path());
+        }
+    }
+}
+
+/// Removes the staging directories in use when the process is interrupted.
+/// Left to the binary: a library should not take over signal handling.
+pub fn clean_up_on_interrupt() {
+    HANDLER.call_once(|| {
+        let _ = ctrlc::set_handler(|| {
+            let (mut staged, mut moving) = (false, false);
+            if let Ok(active) = ACTIVE.lock() {
+                for (path, moving_out) in active.iter() {
+                    let _ = fs::remove_dir_all(path);
+                    staged = true;
+                    moving |= *moving_out;
+                }
+            }
+            // Past staging (installing, committing, upgrading) files may
+            // already be on disk.
+            if moving {
+                eprintln!("\nInterrupted, some files were already moved into place.");
+            } else if staged {
+                eprintln!("\nInterrupted, nothing was written.");
+            } else {
+                eprintln!("\nInterrupted.");
+            }
+            std::process::exit(130);
+        });
+    });
+}
@@ -1,17 +1,224 @@
-pub fn get_available_templates() -> Vec<String> {
-    vec![
-        "Flask Portfolio".to_string(),
-        "MERN Stack".to_string(),
-        "Next.js App".to_string(),
-        "React Portfolio".to_string(),
-        "Vue Portfolio".to_string(),
-        "Static HTML".to_string(),
-    ]
-}
+use std::borrow::Cow;
+use std::fs;
+
+use clap::ValueEnum;
+use serde_json::{Map, Value};
+
+use crate::generator::{Ci, Css};
+use crate::package_manager::PackageManager;
+use crate::render::Variables;
 
+pub mod custom;
 pub mod flask;
 pub mod mern;
 pub mod nextjs;
 pub mod react;
 pub mod vue;
 pub mod static_html;
+
+/// Generation options a template may support beyond its default output.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Feature {
+    TypeScript,
+    Css,
+    Profile,
+    PackageManager,
+    Docker,
+    Ci,
+    Tests,
+}
+
+impl Feature {
+    pub fn flag(self) -> &'static str {
+        match self {
+            Feature::TypeScript => "--typescript",
+            Feature::Css => "--css",
+            Feature::Profile => "--profile",
+            Feature::PackageManager => "--package-manager",
+            Feature::Docker => "--docker",
+            Feature::Ci => "--ci",
+            Feature::Tests => "--tests",
+        }
+    }
+
+    /// Values the flag takes; empty for switches, which are passed on their own.
+    pub fn values(self) -> Vec<&'static str> {
+        match self {
+            Feature::TypeScript | Feature::Docker | Feature::Tests => Vec::new(),
+            Feature::Css => Css::value_variants().iter().map(|css| css.as_str()).collect(),
+            Feature::Profile => vec!["<FILE>"],
+            Feature::Ci => Ci::value_variants().iter().map(|ci| ci.as_str()).collect(),
+            Feature::PackageManager => PackageManager::value_variants()
+                .iter()
+                .map(|pm| pm.as_str())
+                .collect(),
+        }
+    }
+}
+
+/// `.yarnrc.yml` for Yarn, which otherwise installs with Plug'n'Play and
+/// breaks tools that expect a `node_modules` folder.
+pub fn package_manager_files(vars: &Variables) -> Vec<TemplateFile> {
+    match vars.text("package_manager") {
+        "yarn" => vec![TemplateFile::new(".yarnrc.yml", "nodeLinker: node-modules\n")],
+        _ => Vec::new(),
+    }
+}
+
+/// nginx site for the `--docker` images of the static front ends. Unknown
+/// paths fall back to index.html so client-side routes survive a reload.
+pub const NGINX_CONF: &str = r###"server {
+    listen 80;
+    server_name _;
+    root /usr/share/nginx/html;
+    index index.html;
+
+    location / {
+        try_files $uri $uri/ /index.html;
+    }
+
+    location ~* \.(?:css|js|svg|png|jpe?g|gif|ico|woff2?)$ {
+        expires 7d;
+        add_header Cache-Control "public";
+    }
+}
+"###;
+
+/// `.dockerignore` for the Node templates: the image installs and builds
+/// its own dependencies and output.
+pub const NODE_DOCKERIGNORE: &str = r###"node_modules
+npm-debug.log*
+yarn-debug.log*
+yarn-error.log*
+.pnpm-store
+.yarn
+build
+dist
+.next
+coverage
+.git
+.gitignore
+.env
+.env.*
+Dockerfile
+.dockerignore
+*.md
+"###;
+
+/// The template's workflow for the `--ci` provider, if one was chosen.
+pub fn ci_files(vars: &Variables, github: &'static str, gitlab: &'static str) -> Vec<TemplateFile> {
+    match vars.text("ci") {
+        "github" => vec![TemplateFile::new(".github/workflows/ci.yml", github)],
+        "gitlab" => vec![TemplateFile::new(".gitlab-ci.yml", gitlab)],
+        _ => Vec::new(),
+    }
+}
+
+pub struct TemplateFile {
+    pub path: String,
+    pub source: Cow<'static, str>,
+}
+
+impl TemplateFile {
+    pub fn new(path: impl Into<String>, source: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            path: path.into(),
+            source: source.into(),
+        }
+    }
+}
+
+pub trait Template {
+    /// Canonical name accepted by `--template`.
+    fn id(&self) -> &str;
+
+    /// Other names accepted by `--template`.
+    fn aliases(&self) -> Vec<&str>;
+
+    fn display_name(&self) -> &str;
+
+    fn description(&self) -> &str;
+
+    fn features(&self) -> &[Feature] {
+        &[]
+    }
+
+    /// Default values for variables specific to this template.
+    fn variables(&self) -> Map<String, Value> {
+        Map::new()
+    }
+
+    /// Files to render, with paths relative to the project root.
+    fn files(&self, vars: &Variables) -> Vec<TemplateFile>;
+
+    /// Commands to run inside the generated project to get it going.
+    fn next_steps(&self, vars: &Variables) -> Vec<String>;
+}
+
+pub struct Registry {
+    templates: Vec<Box<dyn Template>>,
+    /// Problems found while loading user templates; those templates are skipped.
+    pub warnings: Vec<String>,
+}
+
+impl Registry {
+    pub fn builtin() -> Self {
+        Self {
+            templates: vec![
+                Box::new(flask::Flask),
+                Box::new(mern::Mern),
+                Box::new(nextjs::NextJs),
+                Box::new(react::React),
+                Box::new(vue::Vue),
+                Box::new(static_html::StaticHtml),
+            ],
+            warnings: Vec::new(),
+        }
+    }
+
+    /// Built-in templates plus any registered under the user's config directory.
+    pub fn load() -> Self {
+        let mut registry = Self::builtin();
+        let Some(dir) = custom::user_templates_dir() else {
+            return registry;
+        };
+        let Ok(entries) = fs::read_dir(&dir) else {
+            return registry;
+        };
+
+        let mut roots: Vec<_> = entries
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.join(custom::MANIFEST).is_file())
+            .collect();
+        roots.sort();
+
+        for root in roots {
+            match custom::CustomTemplate::load(&root) {
+                Ok(template) if registry.find(template.id()).is_some() => {
+                    registry.warnings.push(format!(
+                        "Skipping {}: template id '{}' is already taken",
+                        root.display(),
+                        template.id()
+                    ));
+                }
+                Ok(template) => registry.templates.push(Box::new(template)),
+                Err(err) => registry
+                    .warnings
+                    .push(format!("Skipping {}: {:#}", root.display(), err)),
+            }
+        }
+
+        registry
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Template> {
+        self.templates.iter().map(|t| t.as_ref())
+    }
+
+    pub fn find(&self, name: &str) -> Option<&dyn Template> {
+        self.iter().find(|t| {
+            t.id().eq_ignore_ascii_case(name)
+                || t.aliases().iter().any(|alias| alias.eq_ignore_ascii_case(name))
+        })
+    }
+}
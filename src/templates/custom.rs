@@ -0,0 +1,228 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::render::{self, Variables};
+use crate::templates::{Feature, Template, TemplateFile};
+
+pub const MANIFEST: &str = "template.toml";
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Manifest {
+    id: String,
+    name: Option<String>,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    aliases: Vec<String>,
+    #[serde(default)]
+    next_steps: Vec<String>,
+    #[serde(default)]
+    exclude: Vec<String>,
+    #[serde(default)]
+    variables: Map<String, Value>,
+}
+
+/// A template loaded from a directory with a `template.toml` manifest.
+pub struct CustomTemplate {
+    manifest: Manifest,
+    files: Vec<(String, String)>,
+}
+
+impl CustomTemplate {
+    pub fn load(root: &Path) -> Result<Self> {
+        let manifest_path = root.join(MANIFEST);
+        let raw = fs::read_to_string(&manifest_path)
+            .with_context(|| format!("Failed to read {}", manifest_path.display()))?;
+        let manifest: Manifest = toml::from_str(&raw)
+            .with_context(|| format!("Invalid manifest {}", manifest_path.display()))?;
+
+        if manifest.id.trim().is_empty() {
+            bail!("{}: `id` must not be empty", manifest_path.display());
+        }
+
+        let mut files = Vec::new();
+        collect_files(root, root, &manifest.exclude, &mut files)?;
+        files.sort();
+
+        Ok(Self { manifest, files })
+    }
+}
+
+fn collect_files(root: &Path, dir: &Path, exclude: &[String], files: &mut Vec<(String, String)>) -> Result<()> {
+    for entry in fs::read_dir(dir).with_context(|| format!("Failed to read {}", dir.display()))? {
+        let path = entry?.path();
+        let relative = path
+            .strip_prefix(root)?
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+
+        if relative == MANIFEST
+            || file_name == ".git"
+            || exclude.iter().any(|e| *e == relative || *e == file_name)
+        {
+            continue;
+        }
+
+        if path.is_dir() {
+            collect_files(root, &path, exclude, files)?;
+        } else {
+            let bytes = fs::read(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+            let Ok(content) = String::from_utf8(bytes) else {
+                bail!("{}: binary files are not supported in templates", path.display());
+            };
+            files.push((relative, content));
+        }
+    }
+    Ok(())
+}
+
+impl Template for CustomTemplate {
+    fn id(&self) -> &str {
+        &self.manifest.id
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        self.manifest.aliases.iter().map(String::as_str).collect()
+    }
+
+    fn display_name(&self) -> &str {
+        self.manifest.name.as_deref().unwrap_or(&self.manifest.id)
+    }
+
+    fn description(&self) -> &str {
+        &self.manifest.description
+    }
+
+    /// The package manager only sets variables, which a template may or may not use.
+    fn features(&self) -> &[Feature] {
+        &[Feature::PackageManager]
+    }
+
+    fn variables(&self) -> Map<String, Value> {
+        self.manifest.variables.clone()
+    }
+
+    fn files(&self, _vars: &Variables) -> Vec<TemplateFile> {
+        self.files
+            .iter()
+            .map(|(path, content)| TemplateFile::new(path.clone(), content.clone()))
+            .collect()
+    }
+
+    fn next_steps(&self, vars: &Variables) -> Vec<String> {
+        self.manifest
+            .next_steps
+            .iter()
+            .map(|step| render::render(step, vars).unwrap_or_else(|_| step.clone()))
+            .filter(|step| !step.trim().is_empty())
+            .collect()
+    }
+}
+
+/// `~/.config/webgen/templates` (or the platform equivalent).
+pub fn user_templates_dir() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("webgen").join("templates"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+    use crate::generator::Options;
+    use crate::plan::ProjectPlan;
+    use crate::templates::Registry;
+
+    /// A template directory for `name` holding `files`, as (path, contents).
+    fn template_dir(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let root = env::temp_dir().join(format!("webgen-custom-{}-{}", name, std::process::id()));
+        if root.exists() {
+            fs::remove_dir_all(&root).unwrap();
+        }
+        for (path, contents) in files {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, contents).unwrap();
+        }
+        root
+    }
+
+    fn plan(template: &CustomTemplate) -> Result<ProjectPlan> {
+        ProjectPlan::new("demo", template, &Options::default())
+    }
+
+    #[test]
+    fn loads_the_manifest_and_renders_files() {
+        let root = template_dir(
+            "load",
+            &[
+                (
+                    MANIFEST,
+                    "id = \"starter\"\naliases = [\"st\"]\nexclude = [\"notes.md\"]\n\
+                     next_steps = [\"cd {{project_name}}\"]\n[variables]\ngreeting = \"Hi\"\n",
+                ),
+                ("src/{{package_name}}.txt", "{{greeting}}, {{project_name}}\n"),
+                ("notes.md", "not part of the template\n"),
+            ],
+        );
+        let template = CustomTemplate::load(&root).unwrap();
+
+        assert_eq!(template.id(), "starter");
+        assert_eq!(template.aliases(), ["st"]);
+        assert_eq!(template.display_name(), "starter");
+        let plan = plan(&template).unwrap();
+        let paths: Vec<_> = plan.files.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(paths, ["src/demo.txt", crate::manifest::FILE_NAME]);
+        assert_eq!(plan.files[0].contents, "Hi, demo\n");
+        let vars = Options::default().variables("demo", &template);
+        assert_eq!(template.next_steps(&vars), ["cd demo"]);
+    }
+
+    #[test]
+    fn rejects_invalid_manifests() {
+        let empty = template_dir("empty-id", &[(MANIFEST, "id = \" \"\n")]);
+        let err = CustomTemplate::load(&empty).err().unwrap();
+        assert!(err.to_string().ends_with("`id` must not be empty"), "{}", err);
+
+        let unknown = template_dir("unknown-key", &[(MANIFEST, "id = \"x\"\nversion = 2\n")]);
+        let err = CustomTemplate::load(&unknown).err().unwrap();
+        assert!(err.to_string().starts_with("Invalid manifest"), "{}", err);
+    }
+
+    #[test]
+    fn file_names_rendering_outside_the_project_are_rejected() {
+        for target in ["../../pwned.txt", "/tmp/pwned.txt", "a/./b.txt", "a//b.txt"] {
+            let manifest = format!("id = \"escape\"\n[variables]\ntarget = {:?}\n", target);
+            let root = template_dir("escape", &[(MANIFEST, &manifest), ("{{target}}", "x\n")]);
+            let template = CustomTemplate::load(&root).unwrap();
+
+            let err = plan(&template).err().unwrap();
+            assert_eq!(err.to_string(), "Invalid file name rendered from {{target}}");
+            assert!(format!("{:#}", err).contains("is not a path inside the project"), "{:#}", err);
+        }
+    }
+
+    #[test]
+    fn ids_and_aliases_match_case_insensitively() {
+        let root = template_dir(
+            "case",
+            &[(MANIFEST, "id = \"MyStarter\"\naliases = [\"MS\"]\n"), ("README.md", "hi\n")],
+        );
+        let mut registry = Registry::builtin();
+        registry.templates.push(Box::new(CustomTemplate::load(&root).unwrap()));
+
+        for name in ["MyStarter", "mystarter", "MYSTARTER", "ms", "Ms"] {
+            assert_eq!(registry.find(name).map(|t| t.id()), Some("MyStarter"), "{}", name);
+        }
+        assert_eq!(registry.find("REACT").map(|t| t.id()), Some("react"));
+        assert!(registry.find("my-starter").is_none());
+    }
+}
@@ -1,32 +1,116 @@
-use std::path::Path;
-use anyhow::Result;
-use crate::generator::write_file;
+use serde_json::{json, Map, Value};
 
-pub fn generate(project_path: &Path) -> Result<()> {
-    // app.py
-    write_file(&project_path.join("app.py"), APP_PY)?;
-    
-    // requirements.txt
-    write_file(&project_path.join("requirements.txt"), REQUIREMENTS)?;
-    
-    // Templates
-    write_file(&project_path.join("templates/index.html"), INDEX_HTML)?;
-    write_file(&project_path.join("templates/about.html"), ABOUT_HTML)?;
-    write_file(&project_path.join("templates/projects.html"), PROJECTS_HTML)?;
-    write_file(&project_path.join("templates/contact.html"), CONTACT_HTML)?;
-    write_file(&project_path.join("templates/base.html"), BASE_HTML)?;
-    
-    // Static files
-    write_file(&project_path.join("static/css/style.css"), STYLE_CSS)?;
-    write_file(&project_path.join("static/js/main.js"), MAIN_JS)?;
-    
-    // README
-    write_file(&project_path.join("README.md"), README)?;
-    
-    // .gitignore
-    write_file(&project_path.join(".gitignore"), GITIGNORE)?;
-    
-    Ok(())
+use crate::render::Variables;
+use crate::templates::{self, Feature, Template, TemplateFile};
+
+pub struct Flask;
+
+impl Template for Flask {
+    fn id(&self) -> &str {
+        "flask"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["flask-portfolio"]
+    }
+
+    fn display_name(&self) -> &str {
+        "Flask Portfolio"
+    }
+
+    fn description(&self) -> &str {
+        "Python Flask-based portfolio website with modern UI"
+    }
+
+    fn features(&self) -> &[Feature] {
+        &[Feature::Profile, Feature::Docker, Feature::Ci, Feature::Tests]
+    }
+
+    fn variables(&self) -> Map<String, Value> {
+        let profile = json!({
+            "title": "Full Stack Developer | Designer | Creator",
+            "skills": [
+                { "name": "Frontend", "description": "HTML, CSS, JavaScript, React, Vue" },
+                { "name": "Backend", "description": "Python, Flask, Node.js, Express" },
+                { "name": "Database", "description": "MongoDB, PostgreSQL, MySQL" },
+                { "name": "Tools", "description": "Git, Docker, AWS, CI/CD" },
+            ],
+            "projects": [
+                {
+                    "name": "Project 1",
+                    "description": "A cool project built with Python",
+                    "tech": ["Python", "Flask", "JavaScript"],
+                    "url": "https://github.com/yourusername/project1",
+                },
+                {
+                    "name": "Project 2",
+                    "description": "An awesome web application",
+                    "tech": ["React", "Node.js", "MongoDB"],
+                    "url": "https://github.com/yourusername/project2",
+                },
+            ],
+            "social": [
+                { "network": "GitHub", "url": "https://github.com/yourusername" },
+                { "network": "LinkedIn", "url": "https://linkedin.com/in/yourusername" },
+                { "network": "Twitter", "url": "https://twitter.com/yourusername" },
+            ],
+        });
+        Map::from_iter([("profile".to_string(), profile)])
+    }
+
+    fn files(&self, vars: &Variables) -> Vec<TemplateFile> {
+        let mut files = vec![
+            // app.py
+            TemplateFile::new("app.py", APP_PY),
+
+            // requirements.txt
+            TemplateFile::new("requirements.txt", REQUIREMENTS),
+
+            // Templates
+            TemplateFile::new("templates/index.html", INDEX_HTML),
+            TemplateFile::new("templates/about.html", ABOUT_HTML),
+            TemplateFile::new("templates/projects.html", PROJECTS_HTML),
+            TemplateFile::new("templates/contact.html", CONTACT_HTML),
+            TemplateFile::new("templates/base.html", BASE_HTML),
+
+            // Static files
+            TemplateFile::new("static/css/style.css", STYLE_CSS),
+            TemplateFile::new("static/js/main.js", MAIN_JS),
+
+            // README
+            TemplateFile::new("README.md", README),
+
+            // .gitignore
+            TemplateFile::new(".gitignore", GITIGNORE),
+        ];
+
+        if vars.flag("tests") {
+            files.push(TemplateFile::new("requirements-dev.txt", REQUIREMENTS_DEV));
+            files.push(TemplateFile::new("pytest.ini", PYTEST_INI));
+            files.push(TemplateFile::new("tests/test_app.py", TEST_APP));
+        }
+        if vars.flag("docker") {
+            files.push(TemplateFile::new("Dockerfile", DOCKERFILE));
+            files.push(TemplateFile::new(".dockerignore", DOCKERIGNORE));
+        }
+        files.extend(templates::ci_files(vars, GITHUB_CI, GITLAB_CI));
+
+        files
+    }
+
+    fn next_steps(&self, vars: &Variables) -> Vec<String> {
+        let installed = vars.flag("installed");
+        let mut steps = Vec::new();
+        if !installed {
+            steps.push("python -m venv venv".to_string());
+        }
+        steps.push("source venv/bin/activate  # On Windows: venv\\Scripts\\activate".to_string());
+        if !installed {
+            steps.push("pip install -r requirements.txt".to_string());
+        }
+        steps.push("python app.py".to_string());
+        steps
+    }
 }
 
 const APP_PY: &str = r###"from flask import Flask, render_template, request, jsonify
@@ -40,23 +124,34 @@ def index():
 
 @app.route('/about')
 def about():
+{{#if profile.work}}
+    experience = [
+{{#each profile.work}}
+        {
+            'company': {{company | quote}},
+            'position': {{position | quote}},
+            'period': {{period | quote}},
+            'summary': {{#if summary}}{{summary | quote}}{{else}}''{{/if}},
+            'highlights': [{{#each highlights}}{{this | quote}}{{#unless @last}}, {{/unless}}{{/each}}]
+        },
+{{/each}}
+    ]
+    return render_template('about.html', experience=experience)
+{{else}}
     return render_template('about.html')
+{{/if}}
 
 @app.route('/projects')
 def projects():
     projects_data = [
+{{#each profile.projects}}
         {
-            'title': 'Project 1',
-            'description': 'A cool project built with Python',
-            'tech': ['Python', 'Flask', 'JavaScript'],
-            'github': 'https://github.com/yourusername/project1'
-        },
-        {
-            'title': 'Project 2',
-            'description': 'An awesome web application',
-            'tech': ['React', 'Node.js', 'MongoDB'],
-            'github': 'https://github.com/yourusername/project2'
+            'title': {{name | quote}},
+            'description': {{description | quote}},
+            'tech': [{{#each tech}}{{this | quote}}{{#unless @last}}, {{/unless}}{{/each}}],
+            'github': {{url | quote}}
         },
+{{/each}}
     ]
     return render_template('projects.html', projects=projects_data)
 
@@ -81,14 +176,17 @@ const BASE_HTML: &str = r###"<!DOCTYPE html>
 <head>
     <meta charset="UTF-8">
     <meta name="viewport" content="width=device-width, initial-scale=1.0">
-    <title>{% block title %}Portfolio{% endblock %}</title>
+    <title>{% block title %}{{project_name | html}}{% endblock %}</title>
+{{#if description}}
+    <meta name="description" content="{{description | html}}">
+{{/if}}
     <link rel="stylesheet" href="{{ url_for('static', filename='css/style.css') }}">
 </head>
 <body>
     <nav class="navbar">
         <div class="container">
             <div class="nav-brand">
-                <a href="/">My Portfolio</a>
+                <a href="/">{{project_name | html}}</a>
             </div>
             <ul class="nav-menu">
                 <li><a href="/">Home</a></li>
@@ -105,7 +203,7 @@ const BASE_HTML: &str = r###"<!DOCTYPE html>
 
     <footer>
         <div class="container">
-            <p>&copy; 2024 My Portfolio. Built with Flask.</p>
+            <p>&copy; {{year}} {{author | html}}. Built with Flask.</p>
         </div>
     </footer>
 
@@ -116,13 +214,13 @@ const BASE_HTML: &str = r###"<!DOCTYPE html>
 
 const INDEX_HTML: &str = r###"{% extends "base.html" %}
 
-{% block title %}Home - Portfolio{% endblock %}
+{% block title %}Home - {{project_name | html}}{% endblock %}
 
 {% block content %}
 <section class="hero">
     <div class="container">
-        <h1 class="hero-title">Hi, I'm Your Name</h1>
-        <p class="hero-subtitle">Full Stack Developer | Designer | Creator</p>
+        <h1 class="hero-title">Hi, I'm {{author | html}}</h1>
+        <p class="hero-subtitle">{{profile.title | html}}</p>
         <div class="hero-buttons">
             <a href="/projects" class="btn btn-primary">View Projects</a>
             <a href="/contact" class="btn btn-secondary">Get in Touch</a>
@@ -134,22 +232,12 @@ const INDEX_HTML: &str = r###"{% extends "base.html" %}
     <div class="container">
         <h2>Skills</h2>
         <div class="skills-grid">
+{{#each profile.skills}}
             <div class="skill-card">
-                <h3>Frontend</h3>
-                <p>HTML, CSS, JavaScript, React, Vue</p>
-            </div>
-            <div class="skill-card">
-                <h3>Backend</h3>
-                <p>Python, Flask, Node.js, Express</p>
-            </div>
-            <div class="skill-card">
-                <h3>Database</h3>
-                <p>MongoDB, PostgreSQL, MySQL</p>
-            </div>
-            <div class="skill-card">
-                <h3>Tools</h3>
-                <p>Git, Docker, AWS, CI/CD</p>
+                <h3>{{name | html}}</h3>
+                <p>{{description | html}}</p>
             </div>
+{{/each}}
         </div>
     </div>
 </section>
@@ -158,7 +246,7 @@ const INDEX_HTML: &str = r###"{% extends "base.html" %}
 
 const ABOUT_HTML: &str = r###"{% extends "base.html" %}
 
-{% block title %}About - Portfolio{% endblock %}
+{% block title %}About - {{project_name | html}}{% endblock %}
 
 {% block content %}
 <section class="about-section">
@@ -166,15 +254,38 @@ const ABOUT_HTML: &str = r###"{% extends "base.html" %}
         <h1>About Me</h1>
         <div class="about-content">
             <div class="about-text">
-                <p>Hi! I'm a passionate full-stack developer with experience in building web applications.</p>
+{{#if profile.bio}}
+                <p>{{profile.bio | html}}</p>
+{{else}}
+                <p>Hi! I'm {{author | html}}, a passionate full-stack developer with experience in building web applications.</p>
                 <p>I love creating efficient, scalable, and user-friendly solutions to complex problems.</p>
                 <h3>My Journey</h3>
                 <p>Started coding in 2020 and have been constantly learning and building ever since.</p>
+{{/if}}
             </div>
             <div class="about-image">
                 <div class="placeholder-image">Your Photo Here</div>
             </div>
         </div>
+{{#if profile.work}}
+        <h2>Experience</h2>
+        <ol class="timeline">
+            {% for job in experience %}
+            <li class="timeline-item">
+                <h3>{{ job.position }}{% if job.position and job.company %} · {% endif %}{{ job.company }}</h3>
+                {% if job.period %}<p class="timeline-period">{{ job.period }}</p>{% endif %}
+                {% if job.summary %}<p>{{ job.summary }}</p>{% endif %}
+                {% if job.highlights %}
+                <ul>
+                    {% for highlight in job.highlights %}
+                    <li>{{ highlight }}</li>
+                    {% endfor %}
+                </ul>
+                {% endif %}
+            </li>
+            {% endfor %}
+        </ol>
+{{/if}}
     </div>
 </section>
 {% endblock %}
@@ -182,7 +293,7 @@ const ABOUT_HTML: &str = r###"{% extends "base.html" %}
 
 const PROJECTS_HTML: &str = r###"{% extends "base.html" %}
 
-{% block title %}Projects - Portfolio{% endblock %}
+{% block title %}Projects - {{project_name | html}}{% endblock %}
 
 {% block content %}
 <section class="projects-section">
@@ -209,12 +320,15 @@ const PROJECTS_HTML: &str = r###"{% extends "base.html" %}
 
 const CONTACT_HTML: &str = r###"{% extends "base.html" %}
 
-{% block title %}Contact - Portfolio{% endblock %}
+{% block title %}Contact - {{project_name | html}}{% endblock %}
 
 {% block content %}
 <section class="contact-section">
     <div class="container">
         <h1>Get In Touch</h1>
+{{#if profile.email}}
+        <p class="contact-email">Email me at <a href="mailto:{{profile.email | html}}">{{profile.email | html}}</a> or send a message below.</p>
+{{/if}}
         <form id="contactForm" class="contact-form">
             <div class="form-group">
                 <label for="name">Name</label>
@@ -230,11 +344,13 @@ const CONTACT_HTML: &str = r###"{% extends "base.html" %}
             </div>
             <button type="submit" class="btn btn-primary">Send Message</button>
         </form>
+{{#if profile.social}}
         <div class="social-links">
-            <a href="https://github.com/yourusername">GitHub</a>
-            <a href="https://linkedin.com/in/yourusername">LinkedIn</a>
-            <a href="https://twitter.com/yourusername">Twitter</a>
+{{#each profile.social}}
+            <a href="{{url | html}}">{{network | html}}</a>
+{{/each}}
         </div>
+{{/if}}
     </div>
 </section>
 {% endblock %}
@@ -417,6 +533,47 @@ body {
     margin-bottom: 1rem;
 }
 
+.about-section h2 {
+    margin: 4rem 0 2rem;
+}
+
+.timeline {
+    padding-left: 2rem;
+    border-left: 3px solid var(--primary);
+    list-style: none;
+}
+
+.timeline-item {
+    position: relative;
+    margin-bottom: 2.5rem;
+}
+
+.timeline-item::before {
+    content: '';
+    position: absolute;
+    left: calc(-2rem - 8px);
+    top: 0.4rem;
+    width: 13px;
+    height: 13px;
+    border-radius: 50%;
+    background: var(--primary);
+}
+
+.timeline-item h3 {
+    color: var(--primary);
+}
+
+.timeline-period {
+    color: var(--gray);
+    font-size: 0.9rem;
+    margin-bottom: 0.5rem;
+}
+
+.timeline-item ul {
+    margin-top: 0.5rem;
+    padding-left: 1.25rem;
+}
+
 .placeholder-image {
     background: var(--gray);
     color: white;
@@ -469,6 +626,15 @@ body {
     padding: 80px 0;
 }
 
+.contact-email {
+    text-align: center;
+    margin-top: 1rem;
+}
+
+.contact-email a {
+    color: var(--primary);
+}
+
 .contact-form {
     max-width: 600px;
     margin: 2rem auto;
@@ -577,9 +743,9 @@ document.addEventListener('DOMContentLoaded', function() {
 });
 "###;
 
-const README: &str = r###"# Flask Portfolio Website
+const README: &str = r###"# {{project_name}}
 
-A modern, responsive portfolio website built with Flask.
+{{#if description}}{{description}}{{else}}A modern, responsive portfolio website built with Flask.{{/if}}
 
 ## Features
 
@@ -608,13 +774,43 @@ python app.py
 ```
 
 4. Open your browser and visit: `http://localhost:5000`
+{{#if tests}}
+
+## Testing
+
+The tests in `tests/` use pytest and Flask's test client:
+
+```bash
+pip install -r requirements-dev.txt
+pytest
+```
+{{/if}}
 
 ## Customization
 
 - Edit `templates/*.html` to modify the content
 - Modify `static/css/style.css` to change the styling
+- Update project data in `app.py`, or regenerate with `webgen new --profile`
 - Update `app.py` to add new routes or functionality
 
+{{#if docker}}
+## Docker
+
+The image serves the app with gunicorn on port 5000:
+
+```bash
+docker build -t {{package_name}} .
+docker run --rm -p 5000:5000 {{package_name}}
+```
+
+{{/if}}
+{{#if ci}}
+## Continuous Integration
+
+Every push and pull request installs the requirements, checks the code with flake8 for
+syntax errors and undefined names, and runs pytest once a `tests/` folder exists.
+
+{{/if}}
 ## Deployment
 
 This app can be deployed to:
@@ -628,6 +824,134 @@ This app can be deployed to:
 MIT
 "###;
 
+const DOCKERFILE: &str = r###"FROM python:{{python_version}}-slim
+
+ENV PYTHONDONTWRITEBYTECODE=1 \
+    PYTHONUNBUFFERED=1
+
+WORKDIR /app
+COPY requirements.txt .
+RUN pip install --no-cache-dir -r requirements.txt
+COPY . .
+
+RUN useradd --create-home app
+USER app
+
+EXPOSE 5000
+CMD ["gunicorn", "--bind", "0.0.0.0:5000", "--workers", "2", "app:app"]
+"###;
+
+const DOCKERIGNORE: &str = r###"venv/
+env/
+.venv/
+__pycache__/
+*.py[cod]
+instance/
+.git
+.gitignore
+.env
+Dockerfile
+.dockerignore
+*.md
+"###;
+
+const REQUIREMENTS_DEV: &str = r###"-r requirements.txt
+pytest==8.3.3
+"###;
+
+const PYTEST_INI: &str = r###"[pytest]
+testpaths = tests
+pythonpath = .
+"###;
+
+const TEST_APP: &str = r###"import pytest
+from markupsafe import escape
+
+from app import app
+
+PROJECTS = [
+{{#each profile.projects}}
+    {{name | quote}},
+{{/each}}
+]
+
+
+@pytest.fixture
+def client():
+    app.config.update(TESTING=True)
+    with app.test_client() as client:
+        yield client
+
+
+@pytest.mark.parametrize('path', ['/', '/about', '/projects', '/contact'])
+def test_page_renders(client, path):
+    response = client.get(path)
+    assert response.status_code == 200
+    assert response.mimetype == 'text/html'
+
+
+def test_projects_lists_every_project(client):
+    page = client.get('/projects').get_data(as_text=True)
+    for name in PROJECTS:
+        assert str(escape(name)) in page
+
+
+def test_contact_accepts_a_message(client):
+    response = client.post('/contact', data={
+        'name': 'Ada Lovelace',
+        'email': 'ada@example.com',
+        'message': 'Hello!',
+    })
+    assert response.status_code == 200
+    assert response.get_json()['status'] == 'success'
+
+
+def test_unknown_page_is_not_found(client):
+    assert client.get('/does-not-exist').status_code == 404
+"###;
+
+const GITHUB_CI: &str = r###"name: CI
+
+on:
+  push:
+    branches: [main, master]
+  pull_request:
+
+jobs:
+  test:
+    runs-on: ubuntu-latest
+    steps:
+      - uses: actions/checkout@v4
+      - uses: actions/setup-python@v5
+        with:
+          python-version: "{{python_version}}"
+          cache: pip
+      - name: Install dependencies
+        run: pip install -r requirements.txt flake8 pytest
+      - name: Lint
+        run: flake8 . --count --select=E9,F63,F7,F82 --show-source --statistics
+      - name: Test
+        if: hashFiles('tests/**') != ''
+        run: pytest
+"###;
+
+const GITLAB_CI: &str = r###"image: python:{{python_version}}
+
+variables:
+  PIP_CACHE_DIR: "$CI_PROJECT_DIR/.cache/pip"
+
+cache:
+  paths:
+    - .cache/pip/
+
+test:
+  before_script:
+    - pip install -r requirements.txt flake8 pytest
+  script:
+    - flake8 . --count --select=E9,F63,F7,F82 --show-source --statistics --extend-exclude .cache
+    - if [ -d tests ]; then pytest; fi
+"###;
+
 const GITIGNORE: &str = r###"# Python
 __pycache__/
 *.py[cod]
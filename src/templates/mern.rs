@@ -1,34 +1,115 @@
-use std::path::Path;
-use anyhow::Result;
-use crate::generator::write_file;
-
-pub fn generate(project_path: &Path) -> Result<()> {
-    // Root README
-    write_file(&project_path.join("README.md"), ROOT_README)?;
-    
-    // Backend
-    write_file(&project_path.join("backend/package.json"), BACKEND_PACKAGE)?;
-    write_file(&project_path.join("backend/server.js"), SERVER_JS)?;
-    write_file(&project_path.join("backend/.env.example"), ENV_EXAMPLE)?;
-    write_file(&project_path.join("backend/models/User.js"), USER_MODEL)?;
-    write_file(&project_path.join("backend/routes/api.js"), API_ROUTES)?;
-    
-    // Frontend
-    write_file(&project_path.join("frontend/package.json"), FRONTEND_PACKAGE)?;
-    write_file(&project_path.join("frontend/public/index.html"), FRONTEND_INDEX)?;
-    write_file(&project_path.join("frontend/src/index.js"), FRONTEND_INDEX_JS)?;
-    write_file(&project_path.join("frontend/src/App.js"), FRONTEND_APP)?;
-    write_file(&project_path.join("frontend/src/App.css"), FRONTEND_CSS)?;
-    
-    // .gitignore
-    write_file(&project_path.join(".gitignore"), GITIGNORE)?;
-    
-    Ok(())
+use crate::render::Variables;
+use crate::templates::{self, Feature, Template, TemplateFile};
+
+pub struct Mern;
+
+impl Template for Mern {
+    fn id(&self) -> &str {
+        "mern"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["mern-stack"]
+    }
+
+    fn display_name(&self) -> &str {
+        "MERN Stack"
+    }
+
+    fn description(&self) -> &str {
+        "MongoDB, Express, React, Node.js full-stack application"
+    }
+
+    fn features(&self) -> &[Feature] {
+        &[
+            Feature::TypeScript,
+            Feature::Css,
+            Feature::PackageManager,
+            Feature::Docker,
+            Feature::Ci,
+            Feature::Tests,
+        ]
+    }
+
+    fn files(&self, vars: &Variables) -> Vec<TemplateFile> {
+        let typescript = vars.flag("typescript");
+        let tailwind = vars.flag("tailwind");
+        let (js, jsx) = if typescript { ("ts", "tsx") } else { ("js", "js") };
+        let css_ext = if vars.flag("scss") { "scss" } else { "css" };
+
+        let mut files = vec![
+            // Root README & workspace
+            TemplateFile::new("README.md", ROOT_README),
+            TemplateFile::new("package.json", ROOT_PACKAGE),
+
+            // Backend
+            TemplateFile::new("backend/package.json", BACKEND_PACKAGE),
+            TemplateFile::new(format!("backend/server.{}", js), SERVER_JS),
+            TemplateFile::new("backend/.env.example", ENV_EXAMPLE),
+            TemplateFile::new(format!("backend/models/User.{}", js), USER_MODEL),
+            TemplateFile::new(format!("backend/routes/api.{}", js), API_ROUTES),
+
+            // Frontend
+            TemplateFile::new("frontend/package.json", FRONTEND_PACKAGE),
+            TemplateFile::new("frontend/public/index.html", FRONTEND_INDEX),
+            TemplateFile::new(format!("frontend/src/index.{}", jsx), FRONTEND_INDEX_JS),
+            TemplateFile::new(format!("frontend/src/App.{}", jsx), FRONTEND_APP),
+            TemplateFile::new(
+                format!("frontend/src/App.{}", css_ext),
+                if tailwind { FRONTEND_TAILWIND_CSS } else { FRONTEND_CSS },
+            ),
+
+            // .gitignore
+            TemplateFile::new(".gitignore", GITIGNORE),
+        ];
+
+        if vars.text("package_manager") == "pnpm" {
+            files.push(TemplateFile::new("pnpm-workspace.yaml", PNPM_WORKSPACE));
+        }
+        files.extend(templates::package_manager_files(vars));
+        if typescript {
+            files.push(TemplateFile::new("backend/tsconfig.json", BACKEND_TSCONFIG));
+            files.push(TemplateFile::new("frontend/tsconfig.json", FRONTEND_TSCONFIG));
+            files.push(TemplateFile::new("frontend/src/react-app-env.d.ts", REACT_APP_ENV));
+        }
+        if tailwind {
+            files.push(TemplateFile::new("frontend/tailwind.config.js", TAILWIND_CONFIG));
+            files.push(TemplateFile::new("frontend/postcss.config.js", POSTCSS_CONFIG));
+        }
+        if vars.flag("tests") {
+            files.push(TemplateFile::new(format!("backend/tests/api.test.{}", js), API_TEST));
+        }
+        if vars.flag("ci") {
+            files.push(TemplateFile::new("backend/.eslintrc.json", BACKEND_ESLINTRC));
+        }
+        files.extend(templates::ci_files(vars, GITHUB_CI, GITLAB_CI));
+        if vars.flag("docker") {
+            files.push(TemplateFile::new("docker-compose.yml", DOCKER_COMPOSE));
+            files.push(TemplateFile::new("backend/Dockerfile", BACKEND_DOCKERFILE));
+            files.push(TemplateFile::new("backend/.dockerignore", templates::NODE_DOCKERIGNORE));
+            files.push(TemplateFile::new("frontend/Dockerfile", FRONTEND_DOCKERFILE));
+            files.push(TemplateFile::new("frontend/nginx.conf", FRONTEND_NGINX_CONF));
+            files.push(TemplateFile::new("frontend/.dockerignore", templates::NODE_DOCKERIGNORE));
+        }
+
+        files
+    }
+
+    fn next_steps(&self, vars: &Variables) -> Vec<String> {
+        let run = vars.text("run_cmd");
+        let mut steps = Vec::new();
+        if !vars.flag("installed") {
+            steps.push(format!("{}  # backend and frontend", vars.text("install_cmd")));
+        }
+        steps.push(format!("{} backend", run));
+        steps.push(format!("{} frontend  # in a second terminal", run));
+        steps
+    }
 }
 
-const ROOT_README: &str = r###"# MERN Stack Application
+const ROOT_README: &str = r###"# {{project_name}}
 
-Full-stack application built with MongoDB, Express, React, and Node.js.
+{{#if description}}{{description}}{{else}}Full-stack application built with MongoDB, Express, React, and Node.js.{{/if}}
 
 ## Project Structure
 
@@ -36,41 +117,77 @@ Full-stack application built with MongoDB, Express, React, and Node.js.
 ├── backend/          # Express API server
 │   ├── models/       # MongoDB models
 │   ├── routes/       # API routes
-│   └── server.js     # Entry point
-└── frontend/         # React application
-    ├── public/
-    └── src/
+│   └── server.{{#if typescript}}ts{{else}}js{{/if}}     # Entry point
+├── frontend/         # React application
+│   ├── public/
+│   └── src/
+└── package.json      # Workspace root
 ```
 
 ## Setup
 
+`backend` and `frontend` are {{package_manager}} workspaces, so one install at the root covers both:
+
+```bash
+{{install_cmd}}
+cp backend/.env.example backend/.env  # Configure your environment variables
+```
+
 ### Backend
 
 ```bash
-cd backend
-npm install
-cp .env.example .env  # Configure your environment variables
-npm start
+{{run_cmd}} backend
+```
+{{#if typescript}}
+
+For production, compile with `{{run_cmd}} build` in `backend/` and run `{{start_cmd}}`.
+{{/if}}
+{{#if tests}}
+
+The API routes are tested with supertest on Node's built-in test runner, with the
+`User` model stubbed so no database is needed:
+
+```bash
+cd backend && {{run_cmd}} test
 ```
+{{/if}}
 
 The backend will run on `http://localhost:5000`
 
 ### Frontend
 
 ```bash
-cd frontend
-npm install
-npm start
+{{run_cmd}} frontend
 ```
 
 The frontend will run on `http://localhost:3000`
 
+{{#if docker}}
+## Docker
+
+`docker-compose.yml` runs MongoDB, the backend and the frontend together:
+
+```bash
+docker compose up --build
+```
+
+The frontend is served by nginx on `http://localhost:3000` and forwards `/api` to the backend, which is also published on `http://localhost:5000`. MongoDB data is kept in the `mongo-data` volume. Set `JWT_SECRET` in your shell or in a `.env` file next to `docker-compose.yml`.
+
+{{/if}}
+{{#if ci}}
+## Continuous Integration
+
+Every push and pull request installs the workspace once, then lints, tests and builds
+`backend` and `frontend` as separate jobs.
+Commit `{{lockfile}}` after your first install: CI caches dependencies by it.
+
+{{/if}}
 ## Environment Variables
 
 Create a `.env` file in the `backend` directory:
 
 ```
-MONGODB_URI=mongodb://localhost:27017/myapp
+MONGODB_URI=mongodb://localhost:27017/{{package_name}}
 PORT=5000
 JWT_SECRET=your_secret_key
 ```
@@ -80,6 +197,15 @@ JWT_SECRET=your_secret_key
 - RESTful API with Express
 - MongoDB database integration
 - React frontend with hooks
+{{#if tailwind}}
+- Tailwind CSS styling
+{{/if}}
+{{#if scss}}
+- SCSS styling
+{{/if}}
+{{#if typescript}}
+- TypeScript on both backend and frontend
+{{/if}}
 - Responsive design
 - User authentication ready
 
@@ -88,15 +214,59 @@ JWT_SECRET=your_secret_key
 MIT
 "###;
 
+const ROOT_PACKAGE: &str = r###"{
+  "name": "{{package_name}}",
+  "version": "1.0.0",
+  "private": true,
+{{#if package_manager_spec}}
+  "packageManager": "{{package_manager_spec}}",
+{{/if}}
+  "workspaces": [
+    "backend",
+    "frontend"
+  ],
+  "scripts": {
+    "backend": "cd backend && {{#if typescript}}{{run_cmd}} dev{{else}}{{start_cmd}}{{/if}}",
+    "frontend": "cd frontend && {{start_cmd}}"
+  }
+}
+"###;
+
+const PNPM_WORKSPACE: &str = r###"packages:
+  - backend
+  - frontend
+"###;
+
 const BACKEND_PACKAGE: &str = r###"{
-  "name": "mern-backend",
+  "name": "{{package_name}}-backend",
   "version": "1.0.0",
-  "description": "Express backend for MERN app",
+  "description": {{description | json}},
+{{#if typescript}}
+  "main": "dist/server.js",
+  "scripts": {
+    "build": "tsc",
+    "start": "node dist/server.js",
+{{#if ci}}
+    "lint": "eslint --ext .ts .",
+{{/if}}
+{{#if tests}}
+    "test": "tsx --test tests/api.test.ts",
+{{/if}}
+    "dev": "tsx watch server.ts"
+  },
+{{else}}
   "main": "server.js",
   "scripts": {
     "start": "node server.js",
+{{#if ci}}
+    "lint": "eslint .",
+{{/if}}
+{{#if tests}}
+    "test": "node --test tests/api.test.js",
+{{/if}}
     "dev": "nodemon server.js"
   },
+{{/if}}
   "dependencies": {
     "express": "^4.18.2",
     "mongoose": "^8.0.0",
@@ -106,36 +276,77 @@ const BACKEND_PACKAGE: &str = r###"{
     "jsonwebtoken": "^9.0.2"
   },
   "devDependencies": {
-    "nodemon": "^3.0.1"
+{{#if ci}}
+{{#if typescript}}
+    "@typescript-eslint/eslint-plugin": "^7.18.0",
+    "@typescript-eslint/parser": "^7.18.0",
+{{/if}}
+    "eslint": "^8.57.0",
+{{/if}}
+{{#if typescript}}
+    "@types/bcryptjs": "^2.4.6",
+    "@types/cors": "^2.8.17",
+    "@types/express": "^4.17.21",
+    "@types/jsonwebtoken": "^9.0.5",
+    "@types/node": "^20.10.0",
+{{#if tests}}
+    "@types/supertest": "^6.0.2",
+    "supertest": "^7.0.0",
+{{/if}}
+    "tsx": "^4.6.0",
+    "typescript": "^5.3.0"
+{{else}}
+    "nodemon": "^3.0.1"{{#if tests}},
+    "supertest": "^7.0.0"{{/if}}
+{{/if}}
   }
 }
 "###;
 
-const SERVER_JS: &str = r###"const express = require('express');
+const SERVER_JS: &str = r###"{{#if typescript}}
+import express, { Request, Response } from 'express';
+import mongoose from 'mongoose';
+import cors from 'cors';
+import 'dotenv/config';
+import apiRoutes from './routes/api';
+{{else}}
+const express = require('express');
 const mongoose = require('mongoose');
 const cors = require('cors');
 require('dotenv').config();
+{{/if}}
 
 const app = express();
 const PORT = process.env.PORT || 5000;
+const PROJECT_NAME = {{project_name | quote}};
 
 // Middleware
 app.use(cors());
 app.use(express.json());
 
 // MongoDB Connection
-mongoose.connect(process.env.MONGODB_URI || 'mongodb://localhost:27017/myapp', {
+{{#if typescript}}
+mongoose.connect(process.env.MONGODB_URI || 'mongodb://localhost:27017/{{package_name}}')
+{{else}}
+mongoose.connect(process.env.MONGODB_URI || 'mongodb://localhost:27017/{{package_name}}', {
   useNewUrlParser: true,
   useUnifiedTopology: true,
 })
+{{/if}}
 .then(() => console.log('MongoDB connected'))
 .catch(err => console.log('MongoDB connection error:', err));
 
 // Routes
+{{#if typescript}}
+app.use('/api', apiRoutes);
+
+app.get('/', (req: Request, res: Response) => {
+{{else}}
 app.use('/api', require('./routes/api'));
 
 app.get('/', (req, res) => {
-  res.json({ message: 'Welcome to MERN API' });
+{{/if}}
+  res.json({ message: `Welcome to ${PROJECT_NAME} API` });
 });
 
 app.listen(PORT, () => {
@@ -143,14 +354,27 @@ app.listen(PORT, () => {
 });
 "###;
 
-const ENV_EXAMPLE: &str = r###"MONGODB_URI=mongodb://localhost:27017/myapp
+const ENV_EXAMPLE: &str = r###"MONGODB_URI=mongodb://localhost:27017/{{package_name}}
 PORT=5000
 JWT_SECRET=your_secret_key_here
 "###;
 
-const USER_MODEL: &str = r###"const mongoose = require('mongoose');
+const USER_MODEL: &str = r###"{{#if typescript}}
+import { Schema, model } from 'mongoose';
+
+export interface IUser {
+  name: string;
+  email: string;
+  password: string;
+  createdAt: Date;
+}
+
+const userSchema = new Schema<IUser>({
+{{else}}
+const mongoose = require('mongoose');
 
 const userSchema = new mongoose.Schema({
+{{/if}}
   name: {
     type: String,
     required: true
@@ -170,25 +394,36 @@ const userSchema = new mongoose.Schema({
   }
 });
 
+{{#if typescript}}
+export default model<IUser>('User', userSchema);
+{{else}}
 module.exports = mongoose.model('User', userSchema);
+{{/if}}
 "###;
 
-const API_ROUTES: &str = r###"const express = require('express');
+const API_ROUTES: &str = r###"{{#if typescript}}
+import { Router, Request, Response } from 'express';
+import User from '../models/User';
+
+const router = Router();
+{{else}}
+const express = require('express');
 const router = express.Router();
 const User = require('../models/User');
+{{/if}}
 
 // Get all users
-router.get('/users', async (req, res) => {
+router.get('/users', async (req{{#if typescript}}: Request{{/if}}, res{{#if typescript}}: Response{{/if}}) => {
   try {
     const users = await User.find().select('-password');
     res.json(users);
   } catch (error) {
-    res.status(500).json({ message: error.message });
+    res.status(500).json({ message: {{#if typescript}}(error as Error){{else}}error{{/if}}.message });
   }
 });
 
 // Create user
-router.post('/users', async (req, res) => {
+router.post('/users', async (req{{#if typescript}}: Request{{/if}}, res{{#if typescript}}: Response{{/if}}) => {
   const user = new User({
     name: req.body.name,
     email: req.body.email,
@@ -199,23 +434,27 @@ router.post('/users', async (req, res) => {
     const newUser = await user.save();
     res.status(201).json(newUser);
   } catch (error) {
-    res.status(400).json({ message: error.message });
+    res.status(400).json({ message: {{#if typescript}}(error as Error){{else}}error{{/if}}.message });
   }
 });
 
 // Sample data endpoint
-router.get('/data', (req, res) => {
+router.get('/data', (req{{#if typescript}}: Request{{/if}}, res{{#if typescript}}: Response{{/if}}) => {
   res.json({
     message: 'API is working!',
     data: ['Item 1', 'Item 2', 'Item 3']
   });
 });
 
+{{#if typescript}}
+export default router;
+{{else}}
 module.exports = router;
+{{/if}}
 "###;
 
 const FRONTEND_PACKAGE: &str = r###"{
-  "name": "mern-frontend",
+  "name": "{{package_name}}-frontend",
   "version": "0.1.0",
   "private": true,
   "proxy": "http://localhost:5000",
@@ -225,10 +464,42 @@ const FRONTEND_PACKAGE: &str = r###"{
     "react-scripts": "5.0.1",
     "axios": "^1.6.0"
   },
+{{#if typescript}}
+  "devDependencies": {
+    "@types/node": "^20.10.0",
+    "@types/react": "^18.2.0",
+    "@types/react-dom": "^18.2.0",
+{{#if tailwind}}
+    "autoprefixer": "^10.4.16",
+    "postcss": "^8.4.31",
+    "tailwindcss": "^3.4.0",
+{{/if}}
+{{#if scss}}
+    "sass": "^1.69.5",
+{{/if}}
+    "typescript": "^4.9.5"
+  },
+{{else}}
+{{#if tailwind}}
+  "devDependencies": {
+    "autoprefixer": "^10.4.16",
+    "postcss": "^8.4.31",
+    "tailwindcss": "^3.4.0"
+  },
+{{/if}}
+{{#if scss}}
+  "devDependencies": {
+    "sass": "^1.69.5"
+  },
+{{/if}}
+{{/if}}
   "scripts": {
     "start": "react-scripts start",
     "build": "react-scripts build",
     "test": "react-scripts test",
+{{#if ci}}
+    "lint": "eslint --ext .js,.jsx{{#if typescript}},.ts,.tsx{{/if}} src",
+{{/if}}
     "eject": "react-scripts eject"
   },
   "eslintConfig": {
@@ -257,8 +528,8 @@ const FRONTEND_INDEX: &str = r###"<!DOCTYPE html>
     <meta charset="utf-8" />
     <meta name="viewport" content="width=device-width, initial-scale=1" />
     <meta name="theme-color" content="#000000" />
-    <meta name="description" content="MERN Stack Application" />
-    <title>MERN App</title>
+    <meta name="description" content="{{#if description}}{{description | html}}{{else}}MERN Stack Application{{/if}}" />
+    <title>{{project_name | html}}</title>
   </head>
   <body>
     <noscript>You need to enable JavaScript to run this app.</noscript>
@@ -269,10 +540,10 @@ const FRONTEND_INDEX: &str = r###"<!DOCTYPE html>
 
 const FRONTEND_INDEX_JS: &str = r###"import React from 'react';
 import ReactDOM from 'react-dom/client';
-import './App.css';
+import './App.{{css_ext}}';
 import App from './App';
 
-const root = ReactDOM.createRoot(document.getElementById('root'));
+const root = ReactDOM.createRoot(document.getElementById('root'){{#if typescript}} as HTMLElement{{/if}});
 root.render(
   <React.StrictMode>
     <App />
@@ -282,40 +553,47 @@ root.render(
 
 const FRONTEND_APP: &str = r###"import React, { useState, useEffect } from 'react';
 import axios from 'axios';
-import './App.css';
+import './App.{{css_ext}}';
+{{#if typescript}}
+
+interface ApiData {
+  message: string;
+  data?: string[];
+}
+{{/if}}
 
 function App() {
-  const [data, setData] = useState(null);
+  const [data, setData] = useState{{#if typescript}}<ApiData | null>{{/if}}(null);
   const [loading, setLoading] = useState(true);
 
   useEffect(() => {
+    const fetchData = async () => {
+      try {
+        const response = await axios.get{{#if typescript}}<ApiData>{{/if}}('/api/data');
+        setData(response.data);
+        setLoading(false);
+      } catch (error) {
+        console.error('Error fetching data:', error);
+        setLoading(false);
+      }
+    };
+
     fetchData();
   }, []);
 
-  const fetchData = async () => {
-    try {
-      const response = await axios.get('/api/data');
-      setData(response.data);
-      setLoading(false);
-    } catch (error) {
-      console.error('Error fetching data:', error);
-      setLoading(false);
-    }
-  };
-
   return (
-    <div className="App">
-      <header className="App-header">
-        <h1>MERN Stack Application</h1>
-        <div className="content">
+    <div className="{{#if tailwind}}min-h-screen bg-gradient-to-br from-[#667eea] to-[#764ba2] text-center{{else}}App{{/if}}">
+      <header className="{{#if tailwind}}flex min-h-screen flex-col items-center justify-center p-5 text-xl text-white md:text-2xl{{else}}App-header{{/if}}">
+        <h1{{#if tailwind}} className="mb-8 text-4xl font-bold md:text-5xl"{{/if}}>{ {{project_name | json}} }</h1>
+        <div className="{{#if tailwind}}my-8 w-11/12 rounded-xl bg-white/10 p-8 md:w-auto md:min-w-[400px]{{else}}content{{/if}}">
           {loading ? (
             <p>Loading...</p>
           ) : data ? (
             <div>
               <p>{data.message}</p>
-              <ul>
+              <ul{{#if tailwind}} className="mt-4 space-y-2"{{/if}}>
                 {data.data && data.data.map((item, index) => (
-                  <li key={index}>{item}</li>
+                  <li key={index}{{#if tailwind}} className="rounded-md bg-white/20 p-2"{{/if}}>{item}</li>
                 ))}
               </ul>
             </div>
@@ -323,9 +601,9 @@ function App() {
             <p>No data available. Make sure the backend is running!</p>
           )}
         </div>
-        <div className="info">
-          <h3>Stack:</h3>
-          <ul className="stack-list">
+        <div className="{{#if tailwind}}mt-8 rounded-xl bg-white/10 p-6{{else}}info{{/if}}">
+          <h3{{#if tailwind}} className="mb-2 font-semibold"{{/if}}>Stack:</h3>
+          <ul className="{{#if tailwind}}space-y-2 text-left text-lg{{else}}stack-list{{/if}}">
             <li>⚡ MongoDB - Database</li>
             <li>🚀 Express - Backend Framework</li>
             <li>⚛️ React - Frontend Library</li>
@@ -413,6 +691,284 @@ const FRONTEND_CSS: &str = r###".App {
 }
 "###;
 
+const API_TEST: &str = r###"{{#if typescript}}
+import { afterEach, describe, it, mock } from 'node:test';
+import assert from 'node:assert/strict';
+import express from 'express';
+import request from 'supertest';
+import apiRoutes from '../routes/api';
+import User from '../models/User';
+
+const app = express();
+app.use(express.json());
+app.use('/api', apiRoutes);
+{{else}}
+const { afterEach, describe, it, mock } = require('node:test');
+const assert = require('node:assert/strict');
+const express = require('express');
+const request = require('supertest');
+const User = require('../models/User');
+
+const app = express();
+app.use(express.json());
+app.use('/api', require('../routes/api'));
+{{/if}}
+
+afterEach(() => mock.restoreAll());
+
+describe('GET /api/data', () => {
+  it('returns the sample data', async () => {
+    const res = await request(app).get('/api/data').expect(200);
+
+    assert.equal(res.body.message, 'API is working!');
+    assert.deepEqual(res.body.data, ['Item 1', 'Item 2', 'Item 3']);
+  });
+});
+
+describe('GET /api/users', () => {
+  it('lists users without their passwords', async () => {
+    const users = [{ name: 'Ada Lovelace', email: 'ada@example.com' }];
+    const select = mock.fn(async () => users);
+    mock.method(User, 'find', () => ({ select }));
+
+    const res = await request(app).get('/api/users').expect(200);
+
+    assert.deepEqual(res.body, users);
+    assert.deepEqual(select.mock.calls[0].arguments, ['-password']);
+  });
+
+  it('reports database errors', async () => {
+    mock.method(User, 'find', () => ({
+      select: async () => {
+        throw new Error('connection lost');
+      },
+    }));
+
+    const res = await request(app).get('/api/users').expect(500);
+
+    assert.equal(res.body.message, 'connection lost');
+  });
+});
+
+describe('POST /api/users', () => {
+  it('creates a user', async () => {
+    mock.method(User.prototype, 'save', async function ({{#if typescript}}this: unknown{{/if}}) {
+      return this;
+    });
+
+    const res = await request(app)
+      .post('/api/users')
+      .send({ name: 'Ada Lovelace', email: 'ada@example.com', password: 'secret' })
+      .expect(201);
+
+    assert.equal(res.body.name, 'Ada Lovelace');
+    assert.equal(res.body.email, 'ada@example.com');
+  });
+
+  it('rejects invalid users', async () => {
+    const res = await request(app).post('/api/users').send({ name: 'No Email' }).expect(400);
+
+    assert.match(res.body.message, /email/);
+  });
+});
+"###;
+
+const BACKEND_ESLINTRC: &str = r###"{
+  "root": true,
+  "env": {
+    "node": true,
+    "es2022": true
+  },
+{{#if typescript}}
+  "parser": "@typescript-eslint/parser",
+  "plugins": ["@typescript-eslint"],
+  "extends": ["eslint:recommended", "plugin:@typescript-eslint/recommended"],
+{{else}}
+  "extends": "eslint:recommended",
+{{/if}}
+  "parserOptions": {
+    "ecmaVersion": "latest"
+  },
+  "ignorePatterns": ["dist"]
+}
+"###;
+
+const GITHUB_CI: &str = r###"name: CI
+
+on:
+  push:
+    branches: [main, master]
+  pull_request:
+
+jobs:
+  build:
+    name: {{#raw}}${{ matrix.package }}{{/raw}}
+    runs-on: ubuntu-latest
+    strategy:
+      fail-fast: false
+      matrix:
+        package: [backend, frontend]
+    defaults:
+      run:
+        working-directory: {{#raw}}${{ matrix.package }}{{/raw}}
+    steps:
+      - uses: actions/checkout@v4
+{{#if github_setup}}
+      - {{github_setup}}
+{{/if}}
+      - uses: actions/setup-node@v4
+        with:
+          node-version: "{{node_version}}"
+{{#if github_cache}}
+          cache: {{github_cache}}
+{{/if}}
+      - name: Install dependencies
+        working-directory: .
+        run: {{install_cmd}}
+      - name: Lint
+        run: {{run_cmd}} lint
+      - name: Test
+{{#if tests}}
+        if: matrix.package == 'backend' || hashFiles('frontend/src/**/*.test.*') != ''
+{{else}}
+        if: matrix.package == 'frontend' && hashFiles('frontend/src/**/*.test.*') != ''
+{{/if}}
+        run: {{run_cmd}} test
+      - name: Build
+{{#unless typescript}}
+        if: matrix.package == 'frontend'
+{{/unless}}
+        run: {{run_cmd}} build
+"###;
+
+const GITLAB_CI: &str = r###"image: node:{{node_version}}
+
+variables:
+  COREPACK_ENABLE_DOWNLOAD_PROMPT: "0"
+
+cache:
+  key:
+    files:
+      - {{lockfile}}
+  paths:
+    - node_modules/
+    - backend/node_modules/
+    - frontend/node_modules/
+
+build:
+  parallel:
+    matrix:
+      - PACKAGE: [backend, frontend]
+  before_script:
+{{#if setup_cmd}}
+    - {{setup_cmd}}
+{{/if}}
+    - {{install_cmd}}
+    - cd "$PACKAGE"
+  script:
+    - {{run_cmd}} lint
+{{#if tests}}
+    - if [ "$PACKAGE" = backend ] || find src -name '*.test.*' | grep -q .; then {{run_cmd}} test; fi
+{{else}}
+    - if [ "$PACKAGE" = frontend ] && find src -name '*.test.*' | grep -q .; then {{run_cmd}} test; fi
+{{/if}}
+{{#if typescript}}
+    - {{run_cmd}} build
+{{else}}
+    - if [ "$PACKAGE" = frontend ]; then {{run_cmd}} build; fi
+{{/if}}
+"###;
+
+const DOCKER_COMPOSE: &str = r###"services:
+  mongo:
+    image: mongo:7
+    restart: unless-stopped
+    volumes:
+      - mongo-data:/data/db
+
+  backend:
+    build: ./backend
+    restart: unless-stopped
+    environment:
+      MONGODB_URI: mongodb://mongo:27017/{{package_name}}
+      PORT: 5000
+      JWT_SECRET: ${JWT_SECRET:-change-me}
+    ports:
+      - "5000:5000"
+    depends_on:
+      - mongo
+
+  frontend:
+    build: ./frontend
+    restart: unless-stopped
+    ports:
+      - "3000:80"
+    depends_on:
+      - backend
+
+volumes:
+  mongo-data:
+"###;
+
+const BACKEND_DOCKERFILE: &str = r###"{{#if typescript}}
+FROM node:{{node_version}}-alpine AS build
+WORKDIR /app
+{{docker_install}}
+COPY . .
+RUN {{run_cmd}} build
+
+FROM node:{{node_version}}-alpine
+WORKDIR /app
+ENV NODE_ENV=production
+COPY --from=build /app/package.json ./
+COPY --from=build /app/node_modules ./node_modules
+COPY --from=build /app/dist ./dist
+USER node
+EXPOSE 5000
+CMD ["node", "dist/server.js"]
+{{else}}
+FROM node:{{node_version}}-alpine
+WORKDIR /app
+ENV NODE_ENV=production
+{{docker_install}}
+COPY . .
+USER node
+EXPOSE 5000
+CMD ["node", "server.js"]
+{{/if}}
+"###;
+
+const FRONTEND_DOCKERFILE: &str = r###"FROM node:{{node_version}}-alpine AS build
+WORKDIR /app
+{{docker_install}}
+COPY . .
+RUN {{run_cmd}} build
+
+FROM nginx:1.27-alpine
+COPY nginx.conf /etc/nginx/conf.d/default.conf
+COPY --from=build /app/build /usr/share/nginx/html
+EXPOSE 80
+"###;
+
+// The compose service name `backend` doubles as its hostname.
+const FRONTEND_NGINX_CONF: &str = r###"server {
+    listen 80;
+    server_name _;
+    root /usr/share/nginx/html;
+    index index.html;
+
+    location /api/ {
+        proxy_pass http://backend:5000;
+        proxy_set_header Host $host;
+        proxy_set_header X-Forwarded-For $proxy_add_x_forwarded_for;
+    }
+
+    location / {
+        try_files $uri $uri/ /index.html;
+    }
+}
+"###;
+
 const GITIGNORE: &str = r###"# Dependencies
 node_modules/
 /backend/node_modules
@@ -425,6 +981,9 @@ node_modules/
 
 # Production
 /frontend/build
+{{#if typescript}}
+/backend/dist
+{{/if}}
 
 # Misc
 .DS_Store
@@ -435,3 +994,67 @@ yarn-error.log*
 "###;
 
 
+
+const BACKEND_TSCONFIG: &str = r###"{
+  "compilerOptions": {
+    "target": "ES2020",
+    "module": "commonjs",
+    "outDir": "dist",
+    "rootDir": ".",
+    "strict": true,
+    "esModuleInterop": true,
+    "skipLibCheck": true,
+    "forceConsistentCasingInFileNames": true
+  },
+  "include": ["**/*.ts"],
+  "exclude": ["node_modules", "dist"{{#if tests}}, "tests"{{/if}}]
+}
+"###;
+
+const FRONTEND_TSCONFIG: &str = r###"{
+  "compilerOptions": {
+    "target": "es5",
+    "lib": ["dom", "dom.iterable", "esnext"],
+    "allowJs": true,
+    "skipLibCheck": true,
+    "esModuleInterop": true,
+    "allowSyntheticDefaultImports": true,
+    "strict": true,
+    "forceConsistentCasingInFileNames": true,
+    "noFallthroughCasesInSwitch": true,
+    "module": "esnext",
+    "moduleResolution": "node",
+    "resolveJsonModule": true,
+    "isolatedModules": true,
+    "noEmit": true,
+    "jsx": "react-jsx"
+  },
+  "include": ["src"]
+}
+"###;
+
+const REACT_APP_ENV: &str = r###"/// <reference types="react-scripts" />
+"###;
+
+const FRONTEND_TAILWIND_CSS: &str = r###"@tailwind base;
+@tailwind components;
+@tailwind utilities;
+"###;
+
+const TAILWIND_CONFIG: &str = r###"/** @type {import('tailwindcss').Config} */
+module.exports = {
+  content: ['./src/**/*.{js,jsx,ts,tsx}', './public/index.html'],
+  theme: {
+    extend: {},
+  },
+  plugins: [],
+};
+"###;
+
+const POSTCSS_CONFIG: &str = r###"module.exports = {
+  plugins: {
+    tailwindcss: {},
+    autoprefixer: {},
+  },
+};
+"###;
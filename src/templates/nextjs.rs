@@ -1,32 +1,113 @@
-use std::path::Path;
-use anyhow::Result;
-use crate::generator::write_file;
-
-pub fn generate(project_path: &Path) -> Result<()> {
-    write_file(&project_path.join("package.json"), PACKAGE_JSON)?;
-    write_file(&project_path.join("next.config.js"), NEXT_CONFIG)?;
-    write_file(&project_path.join("pages/_app.js"), APP_JS)?;
-    write_file(&project_path.join("pages/index.js"), INDEX_JS)?;
-    write_file(&project_path.join("pages/about.js"), ABOUT_JS)?;
-    write_file(&project_path.join("pages/api/hello.js"), API_HELLO)?;
-    write_file(&project_path.join("styles/globals.css"), GLOBALS_CSS)?;
-    write_file(&project_path.join("components/Layout.js"), LAYOUT)?;
-    write_file(&project_path.join("public/.gitkeep"), "")?;
-    write_file(&project_path.join("README.md"), README)?;
-    write_file(&project_path.join(".gitignore"), GITIGNORE)?;
-    
-    Ok(())
+use crate::render::Variables;
+use crate::templates::{self, Feature, Template, TemplateFile};
+
+pub struct NextJs;
+
+impl Template for NextJs {
+    fn id(&self) -> &str {
+        "nextjs"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["next.js", "next"]
+    }
+
+    fn display_name(&self) -> &str {
+        "Next.js App"
+    }
+
+    fn description(&self) -> &str {
+        "Modern React framework with SSR and routing"
+    }
+
+    fn features(&self) -> &[Feature] {
+        &[
+            Feature::TypeScript,
+            Feature::Css,
+            Feature::PackageManager,
+            Feature::Docker,
+            Feature::Ci,
+            Feature::Tests,
+        ]
+    }
+
+    fn files(&self, vars: &Variables) -> Vec<TemplateFile> {
+        let typescript = vars.flag("typescript");
+        let tailwind = vars.flag("tailwind");
+        let (jsx, js) = if typescript { ("tsx", "ts") } else { ("js", "js") };
+        let css_ext = if vars.flag("scss") { "scss" } else { "css" };
+
+        let mut files = vec![
+            TemplateFile::new("package.json", PACKAGE_JSON),
+            TemplateFile::new("next.config.js", NEXT_CONFIG),
+            TemplateFile::new(format!("pages/_app.{}", jsx), APP_JS),
+            TemplateFile::new(format!("pages/index.{}", jsx), INDEX_JS),
+            TemplateFile::new(format!("pages/about.{}", jsx), ABOUT_JS),
+            TemplateFile::new(format!("pages/api/hello.{}", js), API_HELLO),
+            TemplateFile::new(
+                format!("styles/globals.{}", css_ext),
+                if tailwind { TAILWIND_CSS } else { GLOBALS_CSS },
+            ),
+            TemplateFile::new(format!("components/Layout.{}", jsx), LAYOUT),
+            TemplateFile::new("public/.gitkeep", ""),
+            TemplateFile::new("README.md", README),
+            TemplateFile::new(".gitignore", GITIGNORE),
+        ];
+
+        if typescript {
+            files.push(TemplateFile::new("tsconfig.json", TSCONFIG));
+            files.push(TemplateFile::new("next-env.d.ts", NEXT_ENV));
+        }
+        if tailwind {
+            files.push(TemplateFile::new("tailwind.config.js", TAILWIND_CONFIG));
+            files.push(TemplateFile::new("postcss.config.js", POSTCSS_CONFIG));
+        }
+        if vars.flag("docker") {
+            files.push(TemplateFile::new("Dockerfile", DOCKERFILE));
+            files.push(TemplateFile::new(".dockerignore", templates::NODE_DOCKERIGNORE));
+        }
+        if vars.flag("tests") {
+            files.push(TemplateFile::new("jest.config.js", JEST_CONFIG));
+            files.push(TemplateFile::new(format!("jest.setup.{}", js), JEST_SETUP));
+            files.push(TemplateFile::new(format!("__tests__/index.test.{}", jsx), INDEX_TEST));
+            files.push(TemplateFile::new(format!("__tests__/api/hello.test.{}", js), API_HELLO_TEST));
+        }
+        if vars.flag("ci") {
+            // Without a config `next lint` stops to ask which one to create.
+            files.push(TemplateFile::new(".eslintrc.json", ESLINTRC));
+        }
+        files.extend(templates::package_manager_files(vars));
+        files.extend(templates::ci_files(vars, GITHUB_CI, GITLAB_CI));
+
+        files
+    }
+
+    fn next_steps(&self, vars: &Variables) -> Vec<String> {
+        let mut steps = Vec::new();
+        if !vars.flag("installed") {
+            steps.push(vars.text("install_cmd").to_string());
+        }
+        steps.push(format!("{} dev", vars.text("run_cmd")));
+        steps
+    }
 }
 
 const PACKAGE_JSON: &str = r###"{
-  "name": "nextjs-app",
+  "name": "{{package_name}}",
   "version": "0.1.0",
+{{#if description}}
+  "description": {{description | json}},
+{{/if}}
   "private": true,
+{{#if package_manager_spec}}
+  "packageManager": "{{package_manager_spec}}",
+{{/if}}
   "scripts": {
     "dev": "next dev",
     "build": "next build",
     "start": "next start",
-    "lint": "next lint"
+    "lint": "next lint"{{#if tests}},
+    "test": "jest"{{/if}}
   },
   "dependencies": {
     "next": "14.0.0",
@@ -34,8 +115,29 @@ const PACKAGE_JSON: &str = r###"{
     "react-dom": "^18.2.0"
   },
   "devDependencies": {
+{{#if tests}}
+    "@testing-library/jest-dom": "^6.4.0",
+    "@testing-library/react": "^14.3.1",
+{{#if typescript}}
+    "@types/jest": "^29.5.12",
+{{/if}}
+{{/if}}
+{{#if typescript}}
+    "@types/node": "^20",
+    "@types/react": "^18",
+    "@types/react-dom": "^18",
+{{/if}}
+{{#if tailwind}}
+    "autoprefixer": "^10.0.1",
+{{/if}}
     "eslint": "^8",
-    "eslint-config-next": "14.0.0"
+    "eslint-config-next": "14.0.0"{{#if tests}},
+    "jest": "^29.7.0",
+    "jest-environment-jsdom": "^29.7.0"{{/if}}{{#if tailwind}},
+    "postcss": "^8",
+    "tailwindcss": "^3.3.0"{{/if}}{{#if scss}},
+    "sass": "^1.69.5"{{/if}}{{#if typescript}},
+    "typescript": "^5"{{/if}}
   }
 }
 "###;
@@ -43,49 +145,59 @@ const PACKAGE_JSON: &str = r###"{
 const NEXT_CONFIG: &str = r###"/** @type {import('next').NextConfig} */
 const nextConfig = {
   reactStrictMode: true,
+{{#if docker}}
+  // Bundles a minimal server into .next/standalone for the Docker image.
+  output: 'standalone',
+{{/if}}
 }
 
 module.exports = nextConfig
 "###;
 
-const APP_JS: &str = r###"import '../styles/globals.css'
+const APP_JS: &str = r###"{{#if typescript}}
+import type { AppProps } from 'next/app'
+{{/if}}
+import '../styles/globals.{{css_ext}}'
 
-function MyApp({ Component, pageProps }) {
+function MyApp({ Component, pageProps }{{#if typescript}}: AppProps{{/if}}) {
   return <Component {...pageProps} />
 }
 
 export default MyApp
 "###;
 
-const INDEX_JS: &str = r###"import Layout from '../components/Layout'
+const INDEX_JS: &str = r###"import Link from 'next/link'
+import Layout from '../components/Layout'
 
 export default function Home() {
   return (
     <Layout>
-      <div className="hero">
-        <h1>Welcome to Next.js</h1>
-        <p>A modern React framework with SSR and routing</p>
-        <div className="buttons">
-          <a href="/about" className="btn btn-primary">About</a>
+      <div className="{{#if tailwind}}bg-gradient-to-br from-primary to-secondary px-5 py-24 text-center text-white{{else}}hero{{/if}}">
+        <h1{{#if tailwind}} className="mb-4 text-4xl font-bold md:text-5xl"{{/if}}>Welcome to { {{project_name | json}} }</h1>
+        <p{{#if tailwind}} className="mb-8 text-xl opacity-90 md:text-2xl"{{/if}}>{{#if description}}{ {{description | json}} }{{else}}A modern React framework with SSR and routing{{/if}}</p>
+        <div className="{{#if tailwind}}flex justify-center gap-4{{else}}buttons{{/if}}">
+          <Link href="/about" className="btn {{#if tailwind}}bg-white text-primary hover:-translate-y-0.5 hover:shadow-lg{{else}}btn-primary{{/if}}">About</Link>
+          {/* An API route rather than a page, so a full page load is intended. */}
+          {/* eslint-disable-next-line @next/next/no-html-link-for-pages */}
           <a href="/api/hello" className="btn btn-secondary">API Demo</a>
         </div>
       </div>
       
-      <div className="features">
-        <div className="feature-card">
-          <h3>⚡ Fast</h3>
+      <div className="{{#if tailwind}}mx-auto grid max-w-6xl gap-8 px-5 py-20 sm:grid-cols-2 lg:grid-cols-4{{else}}features{{/if}}">
+        <div className="{{#if tailwind}}rounded-xl bg-white p-8 shadow-lg transition-transform hover:-translate-y-1{{else}}feature-card{{/if}}">
+          <h3{{#if tailwind}} className="mb-4 text-lg font-semibold text-primary"{{/if}}>⚡ Fast</h3>
           <p>Server-side rendering and static generation</p>
         </div>
-        <div className="feature-card">
-          <h3>🔄 File-based Routing</h3>
+        <div className="{{#if tailwind}}rounded-xl bg-white p-8 shadow-lg transition-transform hover:-translate-y-1{{else}}feature-card{{/if}}">
+          <h3{{#if tailwind}} className="mb-4 text-lg font-semibold text-primary"{{/if}}>🔄 File-based Routing</h3>
           <p>Automatic routing based on file structure</p>
         </div>
-        <div className="feature-card">
-          <h3>🎨 CSS Support</h3>
+        <div className="{{#if tailwind}}rounded-xl bg-white p-8 shadow-lg transition-transform hover:-translate-y-1{{else}}feature-card{{/if}}">
+          <h3{{#if tailwind}} className="mb-4 text-lg font-semibold text-primary"{{/if}}>🎨 CSS Support</h3>
           <p>Built-in CSS and Sass support</p>
         </div>
-        <div className="feature-card">
-          <h3>📦 API Routes</h3>
+        <div className="{{#if tailwind}}rounded-xl bg-white p-8 shadow-lg transition-transform hover:-translate-y-1{{else}}feature-card{{/if}}">
+          <h3{{#if tailwind}} className="mb-4 text-lg font-semibold text-primary"{{/if}}>📦 API Routes</h3>
           <p>Build APIs with serverless functions</p>
         </div>
       </div>
@@ -94,30 +206,42 @@ export default function Home() {
 }
 "###;
 
-const ABOUT_JS: &str = r###"import Layout from '../components/Layout'
+const ABOUT_JS: &str = r###"import Link from 'next/link'
+import Layout from '../components/Layout'
 
 export default function About() {
   return (
     <Layout>
-      <div className="page-content">
-        <h1>About This App</h1>
+      <div className="{{#if tailwind}}mx-auto max-w-3xl px-5 py-20{{else}}page-content{{/if}}">
+        <h1{{#if tailwind}} className="mb-8 text-4xl font-bold"{{/if}}>About This App</h1>
         <p>This is a Next.js template application.</p>
         <p>It includes:</p>
-        <ul>
+        <ul{{#if tailwind}} className="my-8 list-disc space-y-2 pl-8"{{/if}}>
           <li>Server-side rendering (SSR)</li>
           <li>Static site generation (SSG)</li>
           <li>API routes</li>
           <li>File-based routing</li>
           <li>Optimized performance</li>
         </ul>
-        <a href="/" className="btn btn-primary">Back to Home</a>
+        <Link href="/" className="btn btn-primary">Back to Home</Link>
       </div>
     </Layout>
   )
 }
 "###;
 
-const API_HELLO: &str = r###"export default function handler(req, res) {
+const API_HELLO: &str = r###"{{#if typescript}}
+import type { NextApiRequest, NextApiResponse } from 'next'
+
+type Data = {
+  message: string
+  timestamp: string
+}
+
+export default function handler(req: NextApiRequest, res: NextApiResponse<Data>) {
+{{else}}
+export default function handler(req, res) {
+{{/if}}
   res.status(200).json({ 
     message: 'Hello from Next.js API!',
     timestamp: new Date().toISOString()
@@ -296,17 +420,31 @@ body {
 }
 "###;
 
-const LAYOUT: &str = r###"export default function Layout({ children }) {
+const LAYOUT: &str = r###"import Link from 'next/link'
+{{#if typescript}}
+import type { ReactNode } from 'react'
+
+export default function Layout({ children }: { children: ReactNode }) {
+{{else}}
+export default function Layout({ children }) {
+{{/if}}
   return (
     <>
-      <nav className="navbar">
-        <div className="container">
-          <div className="nav-content">
-            <div className="logo">Next.js App</div>
+      <nav className="{{#if tailwind}}bg-white py-4 shadow-md{{else}}navbar{{/if}}">
+        <div className="{{#if tailwind}}mx-auto max-w-6xl px-5{{else}}container{{/if}}">
+          <div className="{{#if tailwind}}flex items-center justify-between{{else}}nav-content{{/if}}">
+            <div className="{{#if tailwind}}text-2xl font-bold text-primary{{else}}logo{{/if}}">{ {{project_name | json}} }</div>
+{{#if tailwind}}
+            <ul className="flex gap-4 md:gap-8">
+              <li><Link href="/" className="transition-colors hover:text-primary">Home</Link></li>
+              <li><Link href="/about" className="transition-colors hover:text-primary">About</Link></li>
+            </ul>
+{{else}}
             <ul className="nav-links">
-              <li><a href="/">Home</a></li>
-              <li><a href="/about">About</a></li>
+              <li><Link href="/">Home</Link></li>
+              <li><Link href="/about">About</Link></li>
             </ul>
+{{/if}}
           </div>
         </div>
       </nav>
@@ -316,9 +454,9 @@ const LAYOUT: &str = r###"export default function Layout({ children }) {
 }
 "###;
 
-const README: &str = r###"# Next.js Application
+const README: &str = r###"# {{project_name}}
 
-A modern web application built with Next.js.
+{{#if description}}{{description}}{{else}}A modern web application built with Next.js.{{/if}}
 
 ## Features
 
@@ -326,19 +464,30 @@ A modern web application built with Next.js.
 - 📄 Static site generation (SSG)
 - 🔄 File-based routing
 - 📦 API routes
+{{#if tailwind}}
+- 🌊 Tailwind CSS
+{{else}}
+{{#if scss}}
+- 🎨 SCSS support
+{{else}}
 - 🎨 CSS support
+{{/if}}
+{{/if}}
 - ⚛️ React 18
+{{#if typescript}}
+- 🔷 TypeScript
+{{/if}}
 
 ## Getting Started
 
 Install dependencies:
 ```bash
-npm install
+{{install_cmd}}
 ```
 
 Run the development server:
 ```bash
-npm run dev
+{{run_cmd}} dev
 ```
 
 Open [http://localhost:3000](http://localhost:3000) with your browser.
@@ -346,15 +495,43 @@ Open [http://localhost:3000](http://localhost:3000) with your browser.
 ## Build for Production
 
 ```bash
-npm run build
-npm start
+{{run_cmd}} build
+{{start_cmd}}
+```
+
+{{#if docker}}
+## Docker
+
+The image runs the standalone server Next.js builds into `.next/standalone`:
+
+```bash
+docker build -t {{package_name}} .
+docker run --rm -p 3000:3000 {{package_name}}
 ```
 
+{{/if}}
 ## Learn More
 
 - [Next.js Documentation](https://nextjs.org/docs)
 - [Learn Next.js](https://nextjs.org/learn)
 
+{{#if tests}}
+## Testing
+
+```bash
+{{run_cmd}} test
+```
+
+Jest and React Testing Library run everything in `__tests__/`, with `next/jest` applying the app's own compiler settings.
+
+{{/if}}
+{{#if ci}}
+## Continuous Integration
+
+Every push and pull request installs dependencies, lints{{#if tests}}, runs the tests{{/if}} and builds the app.
+Commit `{{lockfile}}` after your first install: CI caches dependencies by it.
+
+{{/if}}
 ## Deploy
 
 Deploy easily to Vercel, Netlify, or any Node.js hosting platform.
@@ -364,6 +541,153 @@ Deploy easily to Vercel, Netlify, or any Node.js hosting platform.
 MIT
 "###;
 
+const DOCKERFILE: &str = r###"FROM node:{{node_version}}-alpine AS build
+WORKDIR /app
+{{docker_install}}
+COPY . .
+ENV NEXT_TELEMETRY_DISABLED=1
+RUN {{run_cmd}} build
+
+FROM node:{{node_version}}-alpine
+WORKDIR /app
+ENV NODE_ENV=production
+ENV NEXT_TELEMETRY_DISABLED=1
+ENV HOSTNAME=0.0.0.0
+ENV PORT=3000
+COPY --from=build /app/public ./public
+COPY --from=build --chown=node:node /app/.next/standalone ./
+COPY --from=build --chown=node:node /app/.next/static ./.next/static
+USER node
+EXPOSE 3000
+CMD ["node", "server.js"]
+"###;
+
+const JEST_CONFIG: &str = r###"const nextJest = require('next/jest')
+
+// Loads next.config.js and .env files and compiles tests with Next's SWC setup.
+const createJestConfig = nextJest({ dir: './' })
+
+module.exports = createJestConfig({
+  testEnvironment: 'jsdom',
+  setupFilesAfterEnv: ['<rootDir>/jest.setup.{{#if typescript}}ts{{else}}js{{/if}}'],
+})
+"###;
+
+const JEST_SETUP: &str = r###"import '@testing-library/jest-dom'
+"###;
+
+const INDEX_TEST: &str = r###"import { render, screen } from '@testing-library/react'
+import Home from '../pages/index'
+
+describe('Home', () => {
+  it('renders the welcome heading', () => {
+    render(<Home />)
+
+    expect(screen.getByRole('heading', { level: 1 })).toHaveTextContent({{project_name | json}})
+  })
+
+  it('links to the about page', () => {
+    render(<Home />)
+
+    for (const link of screen.getAllByRole('link', { name: 'About' })) {
+      expect(link).toHaveAttribute('href', '/about')
+    }
+  })
+})
+"###;
+
+const API_HELLO_TEST: &str = r###"{{#if typescript}}
+import type { NextApiRequest, NextApiResponse } from 'next'
+{{/if}}
+import handler from '../../pages/api/hello'
+
+describe('/api/hello', () => {
+  it('responds with a greeting', () => {
+    const json = jest.fn()
+    const status = jest.fn(() => ({ json }))
+
+{{#if typescript}}
+    handler({} as NextApiRequest, { status } as unknown as NextApiResponse)
+{{else}}
+    handler({}, { status })
+{{/if}}
+
+    expect(status).toHaveBeenCalledWith(200)
+    expect(json).toHaveBeenCalledWith(
+      expect.objectContaining({ message: 'Hello from Next.js API!' })
+    )
+  })
+})
+"###;
+
+const ESLINTRC: &str = r###"{
+  "extends": "next/core-web-vitals"
+}
+"###;
+
+const GITHUB_CI: &str = r###"name: CI
+
+on:
+  push:
+    branches: [main, master]
+  pull_request:
+
+jobs:
+  build:
+    runs-on: ubuntu-latest
+    env:
+      NEXT_TELEMETRY_DISABLED: 1
+    steps:
+      - uses: actions/checkout@v4
+{{#if github_setup}}
+      - {{github_setup}}
+{{/if}}
+      - uses: actions/setup-node@v4
+        with:
+          node-version: "{{node_version}}"
+{{#if github_cache}}
+          cache: {{github_cache}}
+{{/if}}
+      - name: Install dependencies
+        run: {{install_cmd}}
+      - name: Lint
+        run: {{run_cmd}} lint
+{{#if tests}}
+      - name: Test
+        run: {{run_cmd}} test
+{{/if}}
+      - name: Build
+        run: {{run_cmd}} build
+"###;
+
+const GITLAB_CI: &str = r###"image: node:{{node_version}}
+
+variables:
+  COREPACK_ENABLE_DOWNLOAD_PROMPT: "0"
+  NEXT_TELEMETRY_DISABLED: "1"
+
+cache:
+  key:
+    files:
+      - {{lockfile}}
+  paths:
+    - node_modules/
+    - .next/cache/
+
+build:
+  before_script:
+{{#if setup_cmd}}
+    - {{setup_cmd}}
+{{/if}}
+    - {{install_cmd}}
+  script:
+    - {{run_cmd}} lint
+{{#if tests}}
+    - {{run_cmd}} test
+{{/if}}
+    - {{run_cmd}} build
+"###;
+
 const GITIGNORE: &str = r###"# Dependencies
 /node_modules
 /.pnp
@@ -393,6 +717,93 @@ yarn-error.log*
 
 # Vercel
 .vercel
+{{#if typescript}}
+
+# TypeScript
+*.tsbuildinfo
+{{/if}}
+"###;
+
+
+
+const TSCONFIG: &str = r###"{
+  "compilerOptions": {
+    "target": "es5",
+    "lib": ["dom", "dom.iterable", "esnext"],
+    "allowJs": true,
+    "skipLibCheck": true,
+    "strict": true,
+    "noEmit": true,
+    "esModuleInterop": true,
+    "module": "esnext",
+    "moduleResolution": "bundler",
+    "resolveJsonModule": true,
+    "isolatedModules": true,
+    "jsx": "preserve",
+    "incremental": true,
+    "paths": {
+      "@/*": ["./*"]
+    }
+  },
+  "include": ["next-env.d.ts", "**/*.ts", "**/*.tsx"],
+  "exclude": ["node_modules"]
+}
+"###;
+
+const NEXT_ENV: &str = r###"/// <reference types="next" />
+/// <reference types="next/image-types/global" />
+
+// NOTE: This file should not be edited
+// see https://nextjs.org/docs/basic-features/typescript for more information.
 "###;
 
+const TAILWIND_CSS: &str = r###"@tailwind base;
+@tailwind components;
+@tailwind utilities;
 
+@layer base {
+  body {
+    @apply bg-neutral-100 text-neutral-900 antialiased;
+  }
+}
+
+@layer components {
+  .btn {
+    @apply inline-block rounded-md px-7 py-3 font-medium transition;
+  }
+
+  .btn-primary {
+    @apply bg-primary text-white hover:-translate-y-0.5 hover:shadow-lg;
+  }
+
+  .btn-secondary {
+    @apply border-2 border-white text-white hover:bg-white hover:text-primary;
+  }
+}
+"###;
+
+const TAILWIND_CONFIG: &str = r###"/** @type {import('tailwindcss').Config} */
+module.exports = {
+  content: [
+    './pages/**/*.{js,ts,jsx,tsx}',
+    './components/**/*.{js,ts,jsx,tsx}',
+  ],
+  theme: {
+    extend: {
+      colors: {
+        primary: '#0070f3',
+        secondary: '#7928ca',
+      },
+    },
+  },
+  plugins: [],
+};
+"###;
+
+const POSTCSS_CONFIG: &str = r###"module.exports = {
+  plugins: {
+    tailwindcss: {},
+    autoprefixer: {},
+  },
+};
+"###;
@@ -1,45 +1,205 @@
-use std::path::Path;
-use anyhow::Result;
-use crate::generator::write_file;
-
-pub fn generate(project_path: &Path) -> Result<()> {
-    // Package.json
-    write_file(&project_path.join("package.json"), PACKAGE_JSON)?;
-    
-    // Public files
-    write_file(&project_path.join("public/index.html"), PUBLIC_INDEX)?;
-    
-    // Source files
-    write_file(&project_path.join("src/index.js"), INDEX_JS)?;
-    write_file(&project_path.join("src/App.js"), APP_JS)?;
-    write_file(&project_path.join("src/App.css"), APP_CSS)?;
-    write_file(&project_path.join("src/components/Header.js"), HEADER_JS)?;
-    write_file(&project_path.join("src/components/Hero.js"), HERO_JS)?;
-    write_file(&project_path.join("src/components/About.js"), ABOUT_JS)?;
-    write_file(&project_path.join("src/components/Projects.js"), PROJECTS_JS)?;
-    write_file(&project_path.join("src/components/Contact.js"), CONTACT_JS)?;
-    write_file(&project_path.join("src/components/Footer.js"), FOOTER_JS)?;
-    
-    // README & .gitignore
-    write_file(&project_path.join("README.md"), README)?;
-    write_file(&project_path.join(".gitignore"), GITIGNORE)?;
-    
-    Ok(())
+use serde_json::{json, Map, Value};
+
+use crate::render::Variables;
+use crate::templates::{self, Feature, Template, TemplateFile};
+
+pub struct React;
+
+impl Template for React {
+    fn id(&self) -> &str {
+        "react"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["react-portfolio"]
+    }
+
+    fn display_name(&self) -> &str {
+        "React Portfolio"
+    }
+
+    fn description(&self) -> &str {
+        "Single-page portfolio with React, styled with CSS, SCSS or Tailwind"
+    }
+
+    fn features(&self) -> &[Feature] {
+        &[
+            Feature::TypeScript,
+            Feature::Css,
+            Feature::Profile,
+            Feature::PackageManager,
+            Feature::Docker,
+            Feature::Ci,
+            Feature::Tests,
+        ]
+    }
+
+    fn variables(&self) -> Map<String, Value> {
+        let profile = json!({
+            "title": "Full Stack Developer | Designer | Creator",
+            "skills": [
+                { "name": "Frontend", "description": "HTML, CSS, JavaScript, React, Vue" },
+                { "name": "Backend", "description": "Node.js, Python, Flask, Express" },
+                { "name": "Database", "description": "MongoDB, PostgreSQL, MySQL" },
+                { "name": "Tools", "description": "Git, Docker, AWS, CI/CD" },
+            ],
+            "projects": [
+                {
+                    "name": "Project 1",
+                    "description": "A cool project built with React",
+                    "tech": ["React", "Node.js", "MongoDB"],
+                    "url": "https://github.com/yourusername/project1",
+                },
+                {
+                    "name": "Project 2",
+                    "description": "An awesome web application",
+                    "tech": ["Python", "Flask", "PostgreSQL"],
+                    "url": "https://github.com/yourusername/project2",
+                },
+                {
+                    "name": "Project 3",
+                    "description": "A modern mobile app",
+                    "tech": ["React Native", "Firebase"],
+                    "url": "https://github.com/yourusername/project3",
+                },
+            ],
+        });
+        Map::from_iter([("profile".to_string(), profile)])
+    }
+
+    fn files(&self, vars: &Variables) -> Vec<TemplateFile> {
+        let typescript = vars.flag("typescript");
+        let tailwind = vars.flag("tailwind");
+        let ext = if typescript { "tsx" } else { "js" };
+        let css_ext = if vars.flag("scss") { "scss" } else { "css" };
+        let src = |name: &str| format!("src/{}.{}", name, ext);
+
+        let mut files = vec![
+            // Package.json
+            TemplateFile::new("package.json", PACKAGE_JSON),
+
+            // Public files
+            TemplateFile::new("public/index.html", PUBLIC_INDEX),
+
+            // Source files
+            TemplateFile::new(src("index"), INDEX_JS),
+            TemplateFile::new(src("App"), APP_JS),
+            TemplateFile::new(
+                format!("src/App.{}", css_ext),
+                if tailwind { TAILWIND_CSS } else { APP_CSS },
+            ),
+            TemplateFile::new(src("components/Header"), HEADER_JS),
+            TemplateFile::new(src("components/Hero"), HERO_JS),
+            TemplateFile::new(src("components/About"), ABOUT_JS),
+            TemplateFile::new(src("components/Projects"), PROJECTS_JS),
+            TemplateFile::new(src("components/Contact"), CONTACT_JS),
+            TemplateFile::new(src("components/Footer"), FOOTER_JS),
+
+            // README & .gitignore
+            TemplateFile::new("README.md", README),
+            TemplateFile::new(".gitignore", GITIGNORE),
+        ];
+
+        if vars.flag("profile.work") {
+            files.push(TemplateFile::new(src("components/Experience"), EXPERIENCE_JS));
+        }
+        if typescript {
+            files.push(TemplateFile::new("tsconfig.json", TSCONFIG));
+            files.push(TemplateFile::new("src/react-app-env.d.ts", REACT_APP_ENV));
+        }
+        if tailwind {
+            files.push(TemplateFile::new("tailwind.config.js", TAILWIND_CONFIG));
+            files.push(TemplateFile::new("postcss.config.js", POSTCSS_CONFIG));
+        }
+        if vars.flag("tests") {
+            let test = |name: &str| format!("src/{}.test.{}", name, ext);
+            files.push(TemplateFile::new(
+                format!("src/setupTests.{}", if typescript { "ts" } else { "js" }),
+                SETUP_TESTS,
+            ));
+            files.push(TemplateFile::new(test("App"), APP_TEST));
+            files.push(TemplateFile::new(test("components/Projects"), PROJECTS_TEST));
+            files.push(TemplateFile::new(test("components/Contact"), CONTACT_TEST));
+        }
+        if vars.flag("docker") {
+            files.push(TemplateFile::new("Dockerfile", DOCKERFILE));
+            files.push(TemplateFile::new("nginx.conf", templates::NGINX_CONF));
+            files.push(TemplateFile::new(".dockerignore", templates::NODE_DOCKERIGNORE));
+        }
+        files.extend(templates::package_manager_files(vars));
+        files.extend(templates::ci_files(vars, GITHUB_CI, GITLAB_CI));
+
+        files
+    }
+
+    fn next_steps(&self, vars: &Variables) -> Vec<String> {
+        let mut steps = Vec::new();
+        if !vars.flag("installed") {
+            steps.push(vars.text("install_cmd").to_string());
+        }
+        steps.push(vars.text("start_cmd").to_string());
+        steps
+    }
 }
 
 const PACKAGE_JSON: &str = r###"{
-  "name": "react-portfolio",
+  "name": "{{package_name}}",
   "version": "0.1.0",
+{{#if description}}
+  "description": {{description | json}},
+{{/if}}
   "private": true,
+{{#if package_manager_spec}}
+  "packageManager": "{{package_manager_spec}}",
+{{/if}}
   "dependencies": {
+{{#if tests}}
+    "@testing-library/jest-dom": "^5.17.0",
+    "@testing-library/react": "^14.1.2",
+{{#if typescript}}
+    "@types/jest": "^27.5.2",
+{{/if}}
+{{/if}}
     "react": "^18.2.0",
     "react-dom": "^18.2.0",
     "react-scripts": "5.0.1"
   },
+{{#if typescript}}
+  "devDependencies": {
+    "@types/node": "^20.10.0",
+    "@types/react": "^18.2.0",
+    "@types/react-dom": "^18.2.0",
+{{#if tailwind}}
+    "autoprefixer": "^10.4.16",
+    "postcss": "^8.4.31",
+    "tailwindcss": "^3.4.0",
+{{/if}}
+{{#if scss}}
+    "sass": "^1.69.5",
+{{/if}}
+    "typescript": "^4.9.5"
+  },
+{{else}}
+{{#if tailwind}}
+  "devDependencies": {
+    "autoprefixer": "^10.4.16",
+    "postcss": "^8.4.31",
+    "tailwindcss": "^3.4.0"
+  },
+{{/if}}
+{{#if scss}}
+  "devDependencies": {
+    "sass": "^1.69.5"
+  },
+{{/if}}
+{{/if}}
   "scripts": {
     "start": "react-scripts start",
     "build": "react-scripts build",
     "test": "react-scripts test",
+{{#if ci}}
+    "lint": "eslint --ext .js,.jsx{{#if typescript}},.ts,.tsx{{/if}} src",
+{{/if}}
     "eject": "react-scripts eject"
   },
   "eslintConfig": {
@@ -68,8 +228,8 @@ const PUBLIC_INDEX: &str = r###"<!DOCTYPE html>
     <meta charset="utf-8" />
     <meta name="viewport" content="width=device-width, initial-scale=1" />
     <meta name="theme-color" content="#000000" />
-    <meta name="description" content="Portfolio website created with React" />
-    <title>My Portfolio</title>
+    <meta name="description" content="{{#if description}}{{description | html}}{{else}}Portfolio website created with React{{/if}}" />
+    <title>{{project_name | html}}</title>
   </head>
   <body>
     <noscript>You need to enable JavaScript to run this app.</noscript>
@@ -80,10 +240,10 @@ const PUBLIC_INDEX: &str = r###"<!DOCTYPE html>
 
 const INDEX_JS: &str = r###"import React from 'react';
 import ReactDOM from 'react-dom/client';
-import './App.css';
+import './App.{{css_ext}}';
 import App from './App';
 
-const root = ReactDOM.createRoot(document.getElementById('root'));
+const root = ReactDOM.createRoot(document.getElementById('root'){{#if typescript}} as HTMLElement{{/if}});
 root.render(
   <React.StrictMode>
     <App />
@@ -92,20 +252,26 @@ root.render(
 "###;
 
 const APP_JS: &str = r###"import React from 'react';
-import './App.css';
+import './App.{{css_ext}}';
 import Header from './components/Header';
 import Hero from './components/Hero';
 import About from './components/About';
+{{#if profile.work}}
+import Experience from './components/Experience';
+{{/if}}
 import Projects from './components/Projects';
 import Contact from './components/Contact';
 import Footer from './components/Footer';
 
 function App() {
   return (
-    <div className="App">
+    <div className="{{#if tailwind}}min-h-screen bg-slate-50 font-sans text-slate-800 antialiased{{else}}App{{/if}}">
       <Header />
       <Hero />
       <About />
+{{#if profile.work}}
+      <Experience />
+{{/if}}
       <Projects />
       <Contact />
       <Footer />
@@ -357,6 +523,81 @@ body {
   font-size: 1rem;
 }
 
+/* Experience */
+.experience {
+  padding: 80px 0;
+}
+
+.experience h2 {
+  text-align: center;
+  font-size: 2.5rem;
+  margin-bottom: 3rem;
+}
+
+.timeline {
+  max-width: 800px;
+  margin: 0 auto;
+  padding-left: 2rem;
+  border-left: 3px solid var(--primary);
+  list-style: none;
+}
+
+.timeline-item {
+  position: relative;
+  margin-bottom: 2.5rem;
+}
+
+.timeline-item::before {
+  content: '';
+  position: absolute;
+  left: calc(-2rem - 8px);
+  top: 0.4rem;
+  width: 13px;
+  height: 13px;
+  border-radius: 50%;
+  background: var(--primary);
+}
+
+.timeline-item h3 {
+  color: var(--primary);
+}
+
+.timeline-period {
+  color: var(--gray);
+  font-size: 0.9rem;
+  margin-bottom: 0.5rem;
+}
+
+.timeline-item ul {
+  margin-top: 0.5rem;
+  padding-left: 1.25rem;
+}
+
+.about-bio {
+  max-width: 800px;
+  margin: 0 auto 3rem;
+  text-align: center;
+  font-size: 1.1rem;
+}
+
+.contact-email {
+  margin-top: 2rem;
+  text-align: center;
+}
+
+.contact-email a,
+.social-links a {
+  color: var(--primary);
+  font-weight: 500;
+}
+
+.social-links {
+  display: flex;
+  justify-content: center;
+  gap: 2rem;
+  margin-top: 2rem;
+}
+
 /* Footer */
 .footer {
   background: var(--dark);
@@ -380,16 +621,31 @@ const HEADER_JS: &str = r###"import React from 'react';
 
 function Header() {
   return (
-    <header className="header">
-      <div className="container">
-        <div className="logo">My Portfolio</div>
+    <header className="{{#if tailwind}}sticky top-0 z-50 bg-white shadow-md{{else}}header{{/if}}">
+      <div className="{{#if tailwind}}mx-auto flex max-w-6xl items-center justify-between px-5 py-4{{else}}container{{/if}}">
+        <div className="{{#if tailwind}}text-2xl font-bold text-primary{{else}}logo{{/if}}">{ {{project_name | json}} }</div>
         <nav>
+{{#if tailwind}}
+          <ul className="flex gap-4 md:gap-8">
+            <li><a href="#hero" className="transition-colors hover:text-primary">Home</a></li>
+            <li><a href="#about" className="transition-colors hover:text-primary">About</a></li>
+{{#if profile.work}}
+            <li><a href="#experience" className="transition-colors hover:text-primary">Experience</a></li>
+{{/if}}
+            <li><a href="#projects" className="transition-colors hover:text-primary">Projects</a></li>
+            <li><a href="#contact" className="transition-colors hover:text-primary">Contact</a></li>
+          </ul>
+{{else}}
           <ul className="nav">
             <li><a href="#hero">Home</a></li>
             <li><a href="#about">About</a></li>
+{{#if profile.work}}
+            <li><a href="#experience">Experience</a></li>
+{{/if}}
             <li><a href="#projects">Projects</a></li>
             <li><a href="#contact">Contact</a></li>
           </ul>
+{{/if}}
         </nav>
       </div>
     </header>
@@ -403,12 +659,12 @@ const HERO_JS: &str = r###"import React from 'react';
 
 function Hero() {
   return (
-    <section className="hero" id="hero">
-      <div className="container">
-        <h1>Hi, I'm Your Name</h1>
-        <p>Full Stack Developer | Designer | Creator</p>
-        <div className="hero-buttons">
-          <a href="#projects" className="btn btn-primary">View Projects</a>
+    <section className="{{#if tailwind}}bg-gradient-to-br from-primary to-secondary py-24 text-center text-white{{else}}hero{{/if}}" id="hero">
+      <div className="{{#if tailwind}}mx-auto max-w-6xl px-5{{else}}container{{/if}}">
+        <h1{{#if tailwind}} className="mb-4 text-4xl font-bold md:text-5xl"{{else}}{{/if}}>Hi, I'm { {{author | json}} }</h1>
+        <p{{#if tailwind}} className="mb-8 text-xl opacity-90 md:text-2xl"{{else}}{{/if}}>{ {{profile.title | json}} }</p>
+        <div className="{{#if tailwind}}flex justify-center gap-4{{else}}hero-buttons{{/if}}">
+          <a href="#projects" className="btn {{#if tailwind}}bg-white text-primary hover:-translate-y-0.5 hover:shadow-lg{{else}}btn-primary{{/if}}">View Projects</a>
           <a href="#contact" className="btn btn-secondary">Get in Touch</a>
         </div>
       </div>
@@ -420,23 +676,32 @@ export default Hero;
 "###;
 
 const ABOUT_JS: &str = r###"import React from 'react';
+{{#if typescript}}
+
+interface Skill {
+  title: string;
+  description: string;
+}
+{{/if}}
 
 function About() {
-  const skills = [
-    { title: 'Frontend', description: 'HTML, CSS, JavaScript, React, Vue' },
-    { title: 'Backend', description: 'Node.js, Python, Flask, Express' },
-    { title: 'Database', description: 'MongoDB, PostgreSQL, MySQL' },
-    { title: 'Tools', description: 'Git, Docker, AWS, CI/CD' }
+  const skills{{#if typescript}}: Skill[]{{/if}} = [
+{{#each profile.skills}}
+    { title: {{name | quote}}, description: {{description | quote}} }{{#unless @last}},{{/unless}}
+{{/each}}
   ];
 
   return (
-    <section className="about" id="about">
-      <div className="container">
-        <h2>About Me</h2>
-        <div className="about-content">
+    <section className="{{#if tailwind}}py-20{{else}}about{{/if}}" id="about">
+      <div className="{{#if tailwind}}mx-auto max-w-6xl px-5{{else}}container{{/if}}">
+        <h2{{#if tailwind}} className="mb-12 text-center text-4xl font-bold"{{else}}{{/if}}>About Me</h2>
+{{#if profile.bio}}
+        <p className="{{#if tailwind}}mx-auto mb-12 max-w-3xl text-center text-lg{{else}}about-bio{{/if}}">{ {{profile.bio | json}} }</p>
+{{/if}}
+        <div className="{{#if tailwind}}grid gap-8 sm:grid-cols-2 lg:grid-cols-4{{else}}about-content{{/if}}">
           {skills.map((skill, index) => (
-            <div key={index} className="skill-card">
-              <h3>{skill.title}</h3>
+            <div key={index} className="{{#if tailwind}}rounded-xl bg-white p-8 shadow-lg transition-transform hover:-translate-y-1{{else}}skill-card{{/if}}">
+              <h3{{#if tailwind}} className="mb-4 text-lg font-semibold text-primary"{{else}}{{/if}}>{skill.title}</h3>
               <p>{skill.description}</p>
             </div>
           ))}
@@ -450,41 +715,40 @@ export default About;
 "###;
 
 const PROJECTS_JS: &str = r###"import React from 'react';
+{{#if typescript}}
+
+interface Project {
+  title: string;
+  description: string;
+  tech: string[];
+  github: string;
+}
+{{/if}}
 
 function Projects() {
-  const projects = [
+  const projects{{#if typescript}}: Project[]{{/if}} = [
+{{#each profile.projects}}
     {
-      title: 'Project 1',
-      description: 'A cool project built with React',
-      tech: ['React', 'Node.js', 'MongoDB'],
-      github: 'https://github.com/yourusername/project1'
-    },
-    {
-      title: 'Project 2',
-      description: 'An awesome web application',
-      tech: ['Python', 'Flask', 'PostgreSQL'],
-      github: 'https://github.com/yourusername/project2'
-    },
-    {
-      title: 'Project 3',
-      description: 'A modern mobile app',
-      tech: ['React Native', 'Firebase'],
-      github: 'https://github.com/yourusername/project3'
-    }
+      title: {{name | quote}},
+      description: {{description | quote}},
+      tech: [{{#each tech}}{{this | quote}}{{#unless @last}}, {{/unless}}{{/each}}],
+      github: {{url | quote}}
+    }{{#unless @last}},{{/unless}}
+{{/each}}
   ];
 
   return (
-    <section className="projects" id="projects">
-      <div className="container">
-        <h2>My Projects</h2>
-        <div className="projects-grid">
+    <section className="{{#if tailwind}}bg-white py-20{{else}}projects{{/if}}" id="projects">
+      <div className="{{#if tailwind}}mx-auto max-w-6xl px-5{{else}}container{{/if}}">
+        <h2{{#if tailwind}} className="mb-12 text-center text-4xl font-bold"{{else}}{{/if}}>My Projects</h2>
+        <div className="{{#if tailwind}}grid gap-8 md:grid-cols-2 lg:grid-cols-3{{else}}projects-grid{{/if}}">
           {projects.map((project, index) => (
-            <div key={index} className="project-card">
-              <h3>{project.title}</h3>
+            <div key={index} className="{{#if tailwind}}rounded-xl bg-slate-50 p-8 shadow-lg{{else}}project-card{{/if}}">
+              <h3{{#if tailwind}} className="mb-4 text-lg font-semibold text-primary"{{else}}{{/if}}>{project.title}</h3>
               <p>{project.description}</p>
-              <div className="tech-tags">
+              <div className="{{#if tailwind}}my-4 flex flex-wrap gap-2{{else}}tech-tags{{/if}}">
                 {project.tech.map((tech, i) => (
-                  <span key={i} className="tag">{tech}</span>
+                  <span key={i} className="{{#if tailwind}}rounded-full bg-white px-4 py-1 text-sm{{else}}tag{{/if}}">{tech}</span>
                 ))}
               </div>
               <a href={project.github} className="btn btn-primary" target="_blank" rel="noopener noreferrer">
@@ -501,6 +765,64 @@ function Projects() {
 export default Projects;
 "###;
 
+const EXPERIENCE_JS: &str = r###"import React from 'react';
+{{#if typescript}}
+
+interface Job {
+  company: string;
+  position: string;
+  period: string;
+  summary: string;
+  highlights: string[];
+}
+{{/if}}
+
+function Experience() {
+  const jobs{{#if typescript}}: Job[]{{/if}} = [
+{{#each profile.work}}
+    {
+      company: {{company | quote}},
+      position: {{position | quote}},
+      period: {{period | quote}},
+      summary: {{#if summary}}{{summary | quote}}{{else}}''{{/if}},
+      highlights: [{{#each highlights}}{{this | quote}}{{#unless @last}}, {{/unless}}{{/each}}]
+    }{{#unless @last}},{{/unless}}
+{{/each}}
+  ];
+
+  return (
+    <section className="{{#if tailwind}}py-20{{else}}experience{{/if}}" id="experience">
+      <div className="{{#if tailwind}}mx-auto max-w-6xl px-5{{else}}container{{/if}}">
+        <h2{{#if tailwind}} className="mb-12 text-center text-4xl font-bold"{{else}}{{/if}}>Experience</h2>
+        <ol className="{{#if tailwind}}mx-auto max-w-3xl border-l-4 border-primary pl-8{{else}}timeline{{/if}}">
+          {jobs.map((job, index) => (
+            <li key={index} className="{{#if tailwind}}relative mb-10{{else}}timeline-item{{/if}}">
+{{#if tailwind}}
+              <span className="absolute -left-[2.55rem] top-1.5 h-4 w-4 rounded-full bg-primary" />
+{{/if}}
+              <h3{{#if tailwind}} className="text-lg font-semibold text-primary"{{else}}{{/if}}>
+                {job.position}{job.position && job.company ? ' · ' : ''}{job.company}
+              </h3>
+              {job.period && <p className="{{#if tailwind}}mb-2 text-sm text-slate-500{{else}}timeline-period{{/if}}">{job.period}</p>}
+              {job.summary && <p>{job.summary}</p>}
+              {job.highlights.length > 0 && (
+                <ul{{#if tailwind}} className="mt-2 list-disc pl-5"{{else}}{{/if}}>
+                  {job.highlights.map((highlight, i) => (
+                    <li key={i}>{highlight}</li>
+                  ))}
+                </ul>
+              )}
+            </li>
+          ))}
+        </ol>
+      </div>
+    </section>
+  );
+}
+
+export default Experience;
+"###;
+
 const CONTACT_JS: &str = r###"import React, { useState } from 'react';
 
 function Contact() {
@@ -510,13 +832,13 @@ function Contact() {
     message: ''
   });
 
-  const handleSubmit = (e) => {
+  const handleSubmit = (e{{#if typescript}}: React.FormEvent<HTMLFormElement>{{/if}}) => {
     e.preventDefault();
     alert('Message sent! (This is a demo)');
     setFormData({ name: '', email: '', message: '' });
   };
 
-  const handleChange = (e) => {
+  const handleChange = (e{{#if typescript}}: React.ChangeEvent<HTMLInputElement | HTMLTextAreaElement>{{/if}}) => {
     setFormData({
       ...formData,
       [e.target.name]: e.target.value
@@ -524,38 +846,47 @@ function Contact() {
   };
 
   return (
-    <section className="contact" id="contact">
-      <div className="container">
-        <h2>Get In Touch</h2>
-        <form className="contact-form" onSubmit={handleSubmit}>
-          <div className="form-group">
-            <label htmlFor="name">Name</label>
+    <section className="{{#if tailwind}}py-20{{else}}contact{{/if}}" id="contact">
+      <div className="{{#if tailwind}}mx-auto max-w-6xl px-5{{else}}container{{/if}}">
+        <h2{{#if tailwind}} className="mb-12 text-center text-4xl font-bold"{{else}}{{/if}}>Get In Touch</h2>
+        <form className="{{#if tailwind}}mx-auto max-w-xl{{else}}contact-form{{/if}}" onSubmit={handleSubmit}>
+          <div className="{{#if tailwind}}mb-6{{else}}form-group{{/if}}">
+            <label htmlFor="name"{{#if tailwind}} className="mb-2 block font-medium"{{else}}{{/if}}>Name</label>
             <input
               type="text"
               id="name"
+{{#if tailwind}}
+              className="w-full rounded-md border border-slate-300 p-3 focus:border-primary focus:outline-none"
+{{/if}}
               name="name"
               value={formData.name}
               onChange={handleChange}
               required
             />
           </div>
-          <div className="form-group">
-            <label htmlFor="email">Email</label>
+          <div className="{{#if tailwind}}mb-6{{else}}form-group{{/if}}">
+            <label htmlFor="email"{{#if tailwind}} className="mb-2 block font-medium"{{else}}{{/if}}>Email</label>
             <input
               type="email"
               id="email"
+{{#if tailwind}}
+              className="w-full rounded-md border border-slate-300 p-3 focus:border-primary focus:outline-none"
+{{/if}}
               name="email"
               value={formData.email}
               onChange={handleChange}
               required
             />
           </div>
-          <div className="form-group">
-            <label htmlFor="message">Message</label>
+          <div className="{{#if tailwind}}mb-6{{else}}form-group{{/if}}">
+            <label htmlFor="message"{{#if tailwind}} className="mb-2 block font-medium"{{else}}{{/if}}>Message</label>
             <textarea
               id="message"
+{{#if tailwind}}
+              className="w-full rounded-md border border-slate-300 p-3 focus:border-primary focus:outline-none"
+{{/if}}
               name="message"
-              rows="5"
+              rows={5}
               value={formData.message}
               onChange={handleChange}
               required
@@ -563,6 +894,18 @@ function Contact() {
           </div>
           <button type="submit" className="btn btn-primary">Send Message</button>
         </form>
+{{#if profile.email}}
+        <p className="{{#if tailwind}}mt-8 text-center{{else}}contact-email{{/if}}">
+          Or email me at <a href="mailto:{{profile.email | html}}"{{#if tailwind}} className="font-medium text-primary"{{/if}}>{ {{profile.email | json}} }</a>
+        </p>
+{{/if}}
+{{#if profile.social}}
+        <div className="{{#if tailwind}}mt-8 flex justify-center gap-8 font-medium text-primary{{else}}social-links{{/if}}">
+{{#each profile.social}}
+          <a href="{{url | html}}" target="_blank" rel="noopener noreferrer">{ {{network | json}} }</a>
+{{/each}}
+        </div>
+{{/if}}
       </div>
     </section>
   );
@@ -575,9 +918,9 @@ const FOOTER_JS: &str = r###"import React from 'react';
 
 function Footer() {
   return (
-    <footer className="footer">
-      <div className="container">
-        <p>&copy; 2024 My Portfolio. Built with React.</p>
+    <footer className="{{#if tailwind}}bg-slate-800 py-8 text-center text-white{{else}}footer{{/if}}">
+      <div className="{{#if tailwind}}mx-auto max-w-6xl px-5{{else}}container{{/if}}">
+        <p>&copy; {{year}} { {{author | json}} }. Built with React.</p>
       </div>
     </footer>
   );
@@ -586,9 +929,9 @@ function Footer() {
 export default Footer;
 "###;
 
-const README: &str = r###"# React Portfolio
+const README: &str = r###"# {{project_name}}
 
-A modern, responsive portfolio website built with React.
+{{#if description}}{{description}}{{else}}A modern, responsive portfolio website built with React.{{/if}}
 
 ## Features
 
@@ -597,32 +940,74 @@ A modern, responsive portfolio website built with React.
 - 🚀 Projects showcase
 - 📧 Contact form
 - 📱 Fully responsive design
+{{#if tailwind}}
+- 🌊 Tailwind CSS
+{{/if}}
+{{#if scss}}
+- 🎨 SCSS
+{{/if}}
+{{#if typescript}}
+- 🔷 TypeScript
+{{/if}}
 
 ## Getting Started
 
 ### Install dependencies
 ```bash
-npm install
+{{install_cmd}}
 ```
 
 ### Run development server
 ```bash
-npm start
+{{start_cmd}}
 ```
 
 Open [http://localhost:3000](http://localhost:3000) to view it in your browser.
 
 ### Build for production
 ```bash
-npm run build
+{{run_cmd}} build
+```
+{{#if tests}}
+
+### Run tests
+```bash
+{{run_cmd}} test
+```
+
+Jest and React Testing Library run the `*.test.{{#if typescript}}tsx{{else}}js{{/if}}` files next to the components, in watch mode locally and once under CI.
+{{/if}}
+
+{{#if docker}}
+## Docker
+
+The image builds the app and serves it with nginx:
+
+```bash
+docker build -t {{package_name}} .
+docker run --rm -p 3000:80 {{package_name}}
 ```
 
+Open [http://localhost:3000](http://localhost:3000).
+
+{{/if}}
 ## Customization
 
 - Edit components in `src/components/` to modify content
-- Modify `src/App.css` to change styling
-- Update project data in `Projects.js`
+{{#if tailwind}}
+- Style with Tailwind utility classes; theme colors live in `tailwind.config.js`
+{{else}}
+- Modify `src/App.{{css_ext}}` to change styling
+{{/if}}
+- Update project data in `Projects.{{#if typescript}}tsx{{else}}js{{/if}}`, or regenerate with `webgen new --profile`
+
+{{#if ci}}
+## Continuous Integration
 
+Every push and pull request installs dependencies, lints, runs the tests and builds the app.
+Commit `{{lockfile}}` after your first install: CI caches dependencies by it.
+
+{{/if}}
 ## Deploy
 
 This app can be deployed to:
@@ -660,3 +1045,201 @@ yarn-error.log*
 "###;
 
 
+
+const DOCKERFILE: &str = r###"FROM node:{{node_version}}-alpine AS build
+WORKDIR /app
+{{docker_install}}
+COPY . .
+RUN {{run_cmd}} build
+
+FROM nginx:1.27-alpine
+COPY nginx.conf /etc/nginx/conf.d/default.conf
+COPY --from=build /app/build /usr/share/nginx/html
+EXPOSE 80
+"###;
+
+const SETUP_TESTS: &str = r###"import '@testing-library/jest-dom';
+"###;
+
+const APP_TEST: &str = r###"import React from 'react';
+import { render, screen } from '@testing-library/react';
+import App from './App';
+
+test('renders every section', () => {
+  render(<App />);
+
+  expect(screen.getByRole('heading', { level: 1 })).toHaveTextContent("Hi, I'm");
+  expect(screen.getByRole('heading', { name: 'About Me' })).toBeInTheDocument();
+{{#if profile.work}}
+  expect(screen.getByRole('heading', { name: 'Experience' })).toBeInTheDocument();
+{{/if}}
+  expect(screen.getByRole('heading', { name: 'My Projects' })).toBeInTheDocument();
+  expect(screen.getByRole('heading', { name: 'Get In Touch' })).toBeInTheDocument();
+});
+"###;
+
+const PROJECTS_TEST: &str = r###"import React from 'react';
+import { render, screen } from '@testing-library/react';
+import Projects from './Projects';
+
+const titles{{#if typescript}}: string[]{{/if}} = [
+{{#each profile.projects}}
+  {{name | quote}},
+{{/each}}
+];
+
+test('shows a card for every project', () => {
+  render(<Projects />);
+
+  for (const title of titles) {
+    expect(screen.getByRole('heading', { name: title })).toBeInTheDocument();
+  }
+  expect(screen.queryAllByRole('link', { name: 'View on GitHub' })).toHaveLength(titles.length);
+});
+"###;
+
+const CONTACT_TEST: &str = r###"import React from 'react';
+import { fireEvent, render, screen } from '@testing-library/react';
+import Contact from './Contact';
+
+test('clears the form once a message is sent', () => {
+  const alert = jest.spyOn(window, 'alert').mockImplementation(() => {});
+  render(<Contact />);
+
+  fireEvent.change(screen.getByLabelText('Name'), { target: { value: 'Ada Lovelace' } });
+  fireEvent.change(screen.getByLabelText('Email'), { target: { value: 'ada@example.com' } });
+  fireEvent.change(screen.getByLabelText('Message'), { target: { value: 'Hello!' } });
+  fireEvent.click(screen.getByRole('button', { name: 'Send Message' }));
+
+  expect(alert).toHaveBeenCalledTimes(1);
+  expect(screen.getByLabelText('Name')).toHaveValue('');
+  expect(screen.getByLabelText('Message')).toHaveValue('');
+  alert.mockRestore();
+});
+"###;
+
+const GITHUB_CI: &str = r###"name: CI
+
+on:
+  push:
+    branches: [main, master]
+  pull_request:
+
+jobs:
+  build:
+    runs-on: ubuntu-latest
+    steps:
+      - uses: actions/checkout@v4
+{{#if github_setup}}
+      - {{github_setup}}
+{{/if}}
+      - uses: actions/setup-node@v4
+        with:
+          node-version: "{{node_version}}"
+{{#if github_cache}}
+          cache: {{github_cache}}
+{{/if}}
+      - name: Install dependencies
+        run: {{install_cmd}}
+      - name: Lint
+        run: {{run_cmd}} lint
+      - name: Test
+        if: hashFiles('src/**/*.test.*') != ''
+        run: {{run_cmd}} test
+      - name: Build
+        run: {{run_cmd}} build
+"###;
+
+const GITLAB_CI: &str = r###"image: node:{{node_version}}
+
+variables:
+  COREPACK_ENABLE_DOWNLOAD_PROMPT: "0"
+
+cache:
+  key:
+    files:
+      - {{lockfile}}
+  paths:
+    - node_modules/
+
+build:
+  before_script:
+{{#if setup_cmd}}
+    - {{setup_cmd}}
+{{/if}}
+    - {{install_cmd}}
+  script:
+    - {{run_cmd}} lint
+    - if find src -name '*.test.*' | grep -q .; then {{run_cmd}} test; fi
+    - {{run_cmd}} build
+  artifacts:
+    paths:
+      - build/
+"###;
+
+const TSCONFIG: &str = r###"{
+  "compilerOptions": {
+    "target": "es5",
+    "lib": ["dom", "dom.iterable", "esnext"],
+    "allowJs": true,
+    "skipLibCheck": true,
+    "esModuleInterop": true,
+    "allowSyntheticDefaultImports": true,
+    "strict": true,
+    "forceConsistentCasingInFileNames": true,
+    "noFallthroughCasesInSwitch": true,
+    "module": "esnext",
+    "moduleResolution": "node",
+    "resolveJsonModule": true,
+    "isolatedModules": true,
+    "noEmit": true,
+    "jsx": "react-jsx"
+  },
+  "include": ["src"]
+}
+"###;
+
+const REACT_APP_ENV: &str = r###"/// <reference types="react-scripts" />
+"###;
+
+const TAILWIND_CSS: &str = r###"@tailwind base;
+@tailwind components;
+@tailwind utilities;
+
+@layer components {
+  .btn {
+    @apply inline-block cursor-pointer rounded-md px-7 py-3 font-medium transition;
+  }
+
+  .btn-primary {
+    @apply bg-primary text-white hover:-translate-y-0.5 hover:shadow-lg;
+  }
+
+  .btn-secondary {
+    @apply border-2 border-white text-white hover:bg-white hover:text-primary;
+  }
+}
+"###;
+
+const TAILWIND_CONFIG: &str = r###"/** @type {import('tailwindcss').Config} */
+module.exports = {
+  content: ['./src/**/*.{js,jsx,ts,tsx}', './public/index.html'],
+  theme: {
+    extend: {
+      colors: {
+        primary: '#6366f1',
+        secondary: '#8b5cf6',
+      },
+    },
+  },
+  plugins: [],
+};
+"###;
+
+const POSTCSS_CONFIG: &str = r###"module.exports = {
+  plugins: {
+    tailwindcss: {},
+    autoprefixer: {},
+  },
+};
+"###;
@@ -1,15 +1,115 @@
-use std::path::Path;
-use anyhow::Result;
-use crate::generator::write_file;
-
-pub fn generate(project_path: &Path) -> Result<()> {
-    write_file(&project_path.join("index.html"), INDEX_HTML)?;
-    write_file(&project_path.join("css/style.css"), STYLE_CSS)?;
-    write_file(&project_path.join("js/main.js"), MAIN_JS)?;
-    write_file(&project_path.join("README.md"), README)?;
-    write_file(&project_path.join(".gitignore"), GITIGNORE)?;
-    
-    Ok(())
+use serde_json::{json, Map, Value};
+
+use crate::render::Variables;
+use crate::templates::{self, Feature, Template, TemplateFile};
+
+pub struct StaticHtml;
+
+impl Template for StaticHtml {
+    fn id(&self) -> &str {
+        "static"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["static-html", "html"]
+    }
+
+    fn display_name(&self) -> &str {
+        "Static HTML"
+    }
+
+    fn description(&self) -> &str {
+        "Pure HTML/CSS/JS portfolio template"
+    }
+
+    fn features(&self) -> &[Feature] {
+        &[Feature::Css, Feature::Profile, Feature::PackageManager, Feature::Docker]
+    }
+
+    fn variables(&self) -> Map<String, Value> {
+        let profile = json!({
+            "title": "Full Stack Developer",
+            "skills": [
+                { "name": "Frontend", "description": "HTML, CSS, JavaScript, React, Vue", "icon": "💻" },
+                { "name": "Backend", "description": "Node.js, Python, Flask, Express", "icon": "⚙️" },
+                { "name": "Database", "description": "MongoDB, PostgreSQL, MySQL", "icon": "🗄️" },
+                { "name": "Tools", "description": "Git, Docker, AWS", "icon": "🛠️" },
+            ],
+            "projects": [
+                {
+                    "name": "Project 1",
+                    "description": "A cool project built with modern web technologies",
+                    "tech": ["HTML", "CSS", "JavaScript"],
+                    "url": "https://github.com",
+                },
+                {
+                    "name": "Project 2",
+                    "description": "An awesome web application",
+                    "tech": ["React", "Node.js"],
+                    "url": "https://github.com",
+                },
+                {
+                    "name": "Project 3",
+                    "description": "A modern responsive website",
+                    "tech": ["Vue", "Firebase"],
+                    "url": "https://github.com",
+                },
+            ],
+            "social": [
+                { "network": "GitHub", "url": "https://github.com" },
+                { "network": "LinkedIn", "url": "https://linkedin.com" },
+                { "network": "Twitter", "url": "https://twitter.com" },
+            ],
+        });
+        Map::from_iter([("profile".to_string(), profile)])
+    }
+
+    fn files(&self, vars: &Variables) -> Vec<TemplateFile> {
+        let mut files = vec![
+            TemplateFile::new("index.html", INDEX_HTML),
+            TemplateFile::new("js/main.js", MAIN_JS),
+            TemplateFile::new("README.md", README),
+            TemplateFile::new(".gitignore", GITIGNORE),
+        ];
+
+        // Tailwind and SCSS compile into css/style.css, so only the source is generated.
+        if vars.flag("tailwind") {
+            files.push(TemplateFile::new("package.json", PACKAGE_JSON));
+            files.push(TemplateFile::new("tailwind.config.js", TAILWIND_CONFIG));
+            files.push(TemplateFile::new("src/input.css", TAILWIND_CSS));
+        } else if vars.flag("scss") {
+            files.push(TemplateFile::new("package.json", PACKAGE_JSON));
+            files.push(TemplateFile::new("scss/style.scss", STYLE_CSS));
+        } else {
+            files.push(TemplateFile::new("css/style.css", STYLE_CSS));
+        }
+        let compiled = vars.flag("tailwind") || vars.flag("scss");
+        if vars.flag("docker") {
+            files.push(TemplateFile::new(
+                "Dockerfile",
+                if compiled { DOCKERFILE_BUILD } else { DOCKERFILE },
+            ));
+            files.push(TemplateFile::new("nginx.conf", templates::NGINX_CONF));
+            files.push(TemplateFile::new(".dockerignore", templates::NODE_DOCKERIGNORE));
+        }
+        if compiled {
+            files.extend(templates::package_manager_files(vars));
+        }
+
+        files
+    }
+
+    fn next_steps(&self, vars: &Variables) -> Vec<String> {
+        let mut steps = Vec::new();
+        if vars.flag("tailwind") || vars.flag("scss") {
+            if !vars.flag("installed") {
+                steps.push(vars.text("install_cmd").to_string());
+            }
+            steps.push(format!("{} build  # compiles css/style.css", vars.text("run_cmd")));
+        }
+        steps.push("python -m http.server 8000  # or open index.html in your browser".to_string());
+        steps
+    }
 }
 
 const INDEX_HTML: &str = r##"<!DOCTYPE html>
@@ -17,16 +117,42 @@ const INDEX_HTML: &str = r##"<!DOCTYPE html>
 <head>
     <meta charset="UTF-8">
     <meta name="viewport" content="width=device-width, initial-scale=1.0">
-    <title>My Portfolio</title>
+{{#if description}}
+    <meta name="description" content="{{description | html}}">
+{{/if}}
+    <title>{{project_name | html}}</title>
     <link rel="stylesheet" href="css/style.css">
 </head>
+{{#if tailwind}}
+<body class="font-sans leading-relaxed text-slate-800 antialiased">
+    <nav class="fixed top-0 z-50 w-full bg-white shadow-md">
+        <div class="relative mx-auto flex max-w-6xl items-center justify-between px-5 py-4">
+            <div class="text-2xl font-bold text-primary">{{project_name | html}}</div>
+            <ul class="absolute left-0 top-full hidden w-full space-y-4 bg-white py-8 text-center shadow-md md:static md:flex md:w-auto md:gap-8 md:space-y-0 md:py-0 md:shadow-none" id="navMenu">
+                <li><a href="#home" class="font-medium transition-colors hover:text-primary">Home</a></li>
+                <li><a href="#about" class="font-medium transition-colors hover:text-primary">About</a></li>
+{{#if profile.work}}
+                <li><a href="#experience" class="font-medium transition-colors hover:text-primary">Experience</a></li>
+{{/if}}
+                <li><a href="#projects" class="font-medium transition-colors hover:text-primary">Projects</a></li>
+                <li><a href="#contact" class="font-medium transition-colors hover:text-primary">Contact</a></li>
+            </ul>
+            <div class="flex cursor-pointer flex-col gap-1 md:hidden" id="hamburger">
+                <span class="h-0.5 w-6 bg-slate-800"></span>
+                <span class="h-0.5 w-6 bg-slate-800"></span>
+                <span class="h-0.5 w-6 bg-slate-800"></span>
+            </div>
+{{else}}
 <body>
     <nav class="navbar">
         <div class="container">
-            <div class="logo">My Portfolio</div>
+            <div class="logo">{{project_name | html}}</div>
             <ul class="nav-menu" id="navMenu">
                 <li><a href="#home">Home</a></li>
                 <li><a href="#about">About</a></li>
+{{#if profile.work}}
+                <li><a href="#experience">Experience</a></li>
+{{/if}}
                 <li><a href="#projects">Projects</a></li>
                 <li><a href="#contact">Contact</a></li>
             </ul>
@@ -35,113 +161,128 @@ const INDEX_HTML: &str = r##"<!DOCTYPE html>
                 <span></span>
                 <span></span>
             </div>
+{{/if}}
         </div>
     </nav>
 
-    <section class="hero" id="home">
-        <div class="container">
-            <h1 class="hero-title">Hi, I'm Your Name</h1>
-            <p class="hero-subtitle">Full Stack Developer</p>
-            <div class="hero-buttons">
-                <a href="#projects" class="btn btn-primary">View Projects</a>
+    <section class="{{#if tailwind}}flex min-h-screen items-center bg-gradient-to-br from-primary to-secondary pt-16 text-center text-white{{else}}hero{{/if}}" id="home">
+        <div class="{{#if tailwind}}mx-auto w-full max-w-6xl px-5{{else}}container{{/if}}">
+            <h1 class="{{#if tailwind}}mb-4 animate-fade-in-up text-4xl font-bold md:text-5xl{{else}}hero-title{{/if}}">Hi, I'm {{author | html}}</h1>
+            <p class="{{#if tailwind}}mb-8 animate-fade-in-up text-xl opacity-90 [animation-delay:200ms] md:text-2xl{{else}}hero-subtitle{{/if}}">{{profile.title | html}}</p>
+            <div class="{{#if tailwind}}flex animate-fade-in-up flex-col items-center justify-center gap-4 [animation-delay:400ms] sm:flex-row{{else}}hero-buttons{{/if}}">
+                <a href="#projects" class="btn {{#if tailwind}}bg-white text-primary hover:-translate-y-0.5 hover:shadow-lg{{else}}btn-primary{{/if}}">View Projects</a>
                 <a href="#contact" class="btn btn-secondary">Get in Touch</a>
             </div>
         </div>
     </section>
 
-    <section class="about" id="about">
-        <div class="container">
+    <section class="{{#if tailwind}}py-20{{else}}about{{/if}}" id="about">
+        <div class="{{#if tailwind}}mx-auto max-w-6xl px-5{{else}}container{{/if}}">
             <h2 class="section-title">About Me</h2>
-            <div class="skills-grid">
-                <div class="skill-card">
-                    <div class="skill-icon">💻</div>
-                    <h3>Frontend</h3>
-                    <p>HTML, CSS, JavaScript, React, Vue</p>
-                </div>
-                <div class="skill-card">
-                    <div class="skill-icon">⚙️</div>
-                    <h3>Backend</h3>
-                    <p>Node.js, Python, Flask, Express</p>
-                </div>
-                <div class="skill-card">
-                    <div class="skill-icon">🗄️</div>
-                    <h3>Database</h3>
-                    <p>MongoDB, PostgreSQL, MySQL</p>
-                </div>
-                <div class="skill-card">
-                    <div class="skill-icon">🛠️</div>
-                    <h3>Tools</h3>
-                    <p>Git, Docker, AWS</p>
+{{#if profile.bio}}
+            <p class="{{#if tailwind}}mx-auto mb-12 max-w-3xl text-center text-lg{{else}}about-bio{{/if}}">{{profile.bio | html}}</p>
+{{/if}}
+            <div class="{{#if tailwind}}grid gap-8 sm:grid-cols-2 lg:grid-cols-4{{else}}skills-grid{{/if}}">
+{{#each profile.skills}}
+                <div class="{{#if tailwind}}rounded-xl bg-white p-8 text-center shadow-lg transition-transform hover:-translate-y-1{{else}}skill-card{{/if}}">
+{{#if icon}}
+                    <div class="{{#if tailwind}}mb-4 text-5xl{{else}}skill-icon{{/if}}">{{icon | html}}</div>
+{{/if}}
+                    <h3{{#if tailwind}} class="mb-4 text-lg font-semibold text-primary"{{/if}}>{{name | html}}</h3>
+                    <p>{{description | html}}</p>
                 </div>
+{{/each}}
             </div>
         </div>
     </section>
 
-    <section class="projects" id="projects">
-        <div class="container">
+{{#if profile.work}}
+    <section class="{{#if tailwind}}py-20{{else}}experience{{/if}}" id="experience">
+        <div class="{{#if tailwind}}mx-auto max-w-6xl px-5{{else}}container{{/if}}">
+            <h2 class="section-title">Experience</h2>
+            <ol class="{{#if tailwind}}mx-auto max-w-3xl border-l-4 border-primary pl-8{{else}}timeline{{/if}}">
+{{#each profile.work}}
+                <li class="{{#if tailwind}}relative mb-10{{else}}timeline-item{{/if}}">
+{{#if tailwind}}
+                    <span class="absolute -left-[2.55rem] top-1.5 h-4 w-4 rounded-full bg-primary"></span>
+{{/if}}
+                    <h3{{#if tailwind}} class="text-lg font-semibold text-primary"{{/if}}>{{position | html}}{{#if position}}{{#if company}} · {{/if}}{{/if}}{{company | html}}</h3>
+{{#if period}}
+                    <p class="{{#if tailwind}}mb-2 text-sm text-slate-500{{else}}timeline-period{{/if}}">{{period | html}}</p>
+{{/if}}
+{{#if summary}}
+                    <p>{{summary | html}}</p>
+{{/if}}
+{{#if highlights}}
+                    <ul{{#if tailwind}} class="mt-2 list-disc pl-5"{{/if}}>
+{{#each highlights}}
+                        <li>{{this | html}}</li>
+{{/each}}
+                    </ul>
+{{/if}}
+                </li>
+{{/each}}
+            </ol>
+        </div>
+    </section>
+
+{{/if}}
+    <section class="{{#if tailwind}}bg-slate-50 py-20{{else}}projects{{/if}}" id="projects">
+        <div class="{{#if tailwind}}mx-auto max-w-6xl px-5{{else}}container{{/if}}">
             <h2 class="section-title">My Projects</h2>
-            <div class="projects-grid">
-                <div class="project-card">
-                    <h3>Project 1</h3>
-                    <p>A cool project built with modern web technologies</p>
-                    <div class="tech-tags">
-                        <span class="tag">HTML</span>
-                        <span class="tag">CSS</span>
-                        <span class="tag">JavaScript</span>
-                    </div>
-                    <a href="https://github.com" class="btn btn-primary">View</a>
-                </div>
-                <div class="project-card">
-                    <h3>Project 2</h3>
-                    <p>An awesome web application</p>
-                    <div class="tech-tags">
-                        <span class="tag">React</span>
-                        <span class="tag">Node.js</span>
+            <div class="{{#if tailwind}}grid gap-8 md:grid-cols-2 lg:grid-cols-3{{else}}projects-grid{{/if}}">
+{{#each profile.projects}}
+                <div class="{{#if tailwind}}rounded-xl bg-white p-8 shadow-lg{{else}}project-card{{/if}}">
+                    <h3{{#if tailwind}} class="mb-4 text-lg font-semibold text-primary"{{/if}}>{{name | html}}</h3>
+                    <p>{{description | html}}</p>
+                    <div class="{{#if tailwind}}my-4 flex flex-wrap gap-2{{else}}tech-tags{{/if}}">
+{{#each tech}}
+                        <span class="{{#if tailwind}}rounded-full bg-slate-100 px-4 py-1 text-sm{{else}}tag{{/if}}">{{this | html}}</span>
+{{/each}}
                     </div>
-                    <a href="https://github.com" class="btn btn-primary">View</a>
-                </div>
-                <div class="project-card">
-                    <h3>Project 3</h3>
-                    <p>A modern responsive website</p>
-                    <div class="tech-tags">
-                        <span class="tag">Vue</span>
-                        <span class="tag">Firebase</span>
-                    </div>
-                    <a href="https://github.com" class="btn btn-primary">View</a>
+{{#if url}}
+                    <a href="{{url | html}}" class="btn btn-primary">View</a>
+{{/if}}
                 </div>
+{{/each}}
             </div>
         </div>
     </section>
 
-    <section class="contact" id="contact">
-        <div class="container">
+    <section class="{{#if tailwind}}py-20{{else}}contact{{/if}}" id="contact">
+        <div class="{{#if tailwind}}mx-auto max-w-6xl px-5{{else}}container{{/if}}">
             <h2 class="section-title">Get In Touch</h2>
-            <form class="contact-form" id="contactForm">
-                <div class="form-group">
-                    <label for="name">Name</label>
-                    <input type="text" id="name" name="name" required>
+{{#if profile.email}}
+            <p class="{{#if tailwind}}mb-8 text-center{{else}}contact-email{{/if}}">Email me at <a href="mailto:{{profile.email | html}}"{{#if tailwind}} class="font-medium text-primary"{{/if}}>{{profile.email | html}}</a></p>
+{{/if}}
+            <form class="{{#if tailwind}}mx-auto mb-12 max-w-xl{{else}}contact-form{{/if}}" id="contactForm">
+                <div class="{{#if tailwind}}mb-6{{else}}form-group{{/if}}">
+                    <label for="name"{{#if tailwind}} class="mb-2 block font-medium"{{/if}}>Name</label>
+                    <input type="text" id="name" name="name"{{#if tailwind}} class="w-full rounded-md border border-slate-300 p-3 focus:border-primary focus:outline-none"{{/if}} required>
                 </div>
-                <div class="form-group">
-                    <label for="email">Email</label>
-                    <input type="email" id="email" name="email" required>
+                <div class="{{#if tailwind}}mb-6{{else}}form-group{{/if}}">
+                    <label for="email"{{#if tailwind}} class="mb-2 block font-medium"{{/if}}>Email</label>
+                    <input type="email" id="email" name="email"{{#if tailwind}} class="w-full rounded-md border border-slate-300 p-3 focus:border-primary focus:outline-none"{{/if}} required>
                 </div>
-                <div class="form-group">
-                    <label for="message">Message</label>
-                    <textarea id="message" name="message" rows="5" required></textarea>
+                <div class="{{#if tailwind}}mb-6{{else}}form-group{{/if}}">
+                    <label for="message"{{#if tailwind}} class="mb-2 block font-medium"{{/if}}>Message</label>
+                    <textarea id="message" name="message" rows="5"{{#if tailwind}} class="w-full rounded-md border border-slate-300 p-3 focus:border-primary focus:outline-none"{{/if}} required></textarea>
                 </div>
                 <button type="submit" class="btn btn-primary">Send Message</button>
             </form>
-            <div class="social-links">
-                <a href="https://github.com">GitHub</a>
-                <a href="https://linkedin.com">LinkedIn</a>
-                <a href="https://twitter.com">Twitter</a>
+{{#if profile.social}}
+            <div class="{{#if tailwind}}flex justify-center gap-8 font-medium text-primary{{else}}social-links{{/if}}">
+{{#each profile.social}}
+                <a href="{{url | html}}">{{network | html}}</a>
+{{/each}}
             </div>
+{{/if}}
         </div>
     </section>
 
-    <footer class="footer">
-        <div class="container">
-            <p>&copy; 2024 My Portfolio. All rights reserved.</p>
+    <footer class="{{#if tailwind}}bg-slate-800 py-8 text-center text-white{{else}}footer{{/if}}">
+        <div class="{{#if tailwind}}mx-auto max-w-6xl px-5{{else}}container{{/if}}">
+            <p>&copy; {{year}} {{author | html}}. All rights reserved.</p>
         </div>
     </footer>
 
@@ -295,7 +436,7 @@ body {
 }
 
 /* Sections */
-.about, .projects, .contact {
+.about, .experience, .projects, .contact {
     padding: 80px 20px;
 }
 
@@ -306,6 +447,53 @@ body {
     color: var(--dark);
 }
 
+.about-bio {
+    max-width: 800px;
+    margin: 0 auto 3rem;
+    text-align: center;
+    font-size: 1.1rem;
+}
+
+/* Experience Timeline */
+.timeline {
+    max-width: 800px;
+    margin: 0 auto;
+    padding-left: 2rem;
+    border-left: 3px solid var(--primary);
+    list-style: none;
+}
+
+.timeline-item {
+    position: relative;
+    margin-bottom: 2.5rem;
+}
+
+.timeline-item::before {
+    content: '';
+    position: absolute;
+    left: calc(-2rem - 8px);
+    top: 0.4rem;
+    width: 13px;
+    height: 13px;
+    border-radius: 50%;
+    background: var(--primary);
+}
+
+.timeline-item h3 {
+    color: var(--primary);
+}
+
+.timeline-period {
+    color: #64748b;
+    font-size: 0.9rem;
+    margin-bottom: 0.5rem;
+}
+
+.timeline-item ul {
+    margin-top: 0.5rem;
+    padding-left: 1.25rem;
+}
+
 /* Skills Grid */
 .skills-grid {
     display: grid;
@@ -380,6 +568,15 @@ body {
 }
 
 /* Contact Form */
+.contact-email {
+    text-align: center;
+    margin-bottom: 2rem;
+}
+
+.contact-email a {
+    color: var(--primary);
+}
+
 .contact-form {
     max-width: 600px;
     margin: 0 auto 3rem;
@@ -490,7 +687,7 @@ const MAIN_JS: &str = r##"const hamburger = document.getElementById('hamburger')
 const navMenu = document.getElementById('navMenu');
 
 hamburger.addEventListener('click', () => {
-    navMenu.classList.toggle('active');
+    navMenu.classList.toggle('{{#if tailwind}}hidden{{else}}active{{/if}}');
 });
 
 const contactForm = document.getElementById('contactForm');
@@ -512,13 +709,35 @@ document.querySelectorAll('a[href^="#"]').forEach(anchor => {
 });
 "##;
 
-const README: &str = r##"# Static HTML Portfolio
+const README: &str = r##"# {{project_name}}
 
-A modern, responsive portfolio website built with pure HTML, CSS, and JavaScript.
+{{#if description}}{{description}}{{else}}A modern, responsive portfolio website built with pure HTML, CSS, and JavaScript.{{/if}}
 
 ## Getting Started
 
+{{#if tailwind}}
+The stylesheet is built with Tailwind CSS. Install the CLI and compile `css/style.css` first:
+
+```bash
+{{install_cmd}}
+{{run_cmd}} build    # or `{{run_cmd}} watch` while editing
+```
+
+Then open `index.html` in your web browser.
+{{else}}
+{{#if scss}}
+The stylesheet is written in SCSS. Install Sass and compile `css/style.css` first:
+
+```bash
+{{install_cmd}}
+{{run_cmd}} build    # or `{{run_cmd}} watch` while editing
+```
+
+Then open `index.html` in your web browser.
+{{else}}
 Simply open `index.html` in your web browser!
+{{/if}}
+{{/if}}
 
 For development:
 
@@ -528,20 +747,55 @@ python -m http.server 8000
 
 Then open http://localhost:8000
 
-## Structure
+{{#if docker}}
+## Docker
+
+The image serves the site with nginx{{#if tailwind}}, compiling the stylesheet first{{/if}}{{#if scss}}, compiling the stylesheet first{{/if}}:
+
+```bash
+docker build -t {{package_name}} .
+docker run --rm -p 8000:80 {{package_name}}
+```
+
+Then open http://localhost:8000
+
+{{/if}}## Structure
 
 ```
 ├── index.html
+{{#if tailwind}}
+├── tailwind.config.js
+├── src/
+│   └── input.css     # Tailwind directives
+├── css/
+│   └── style.css     # generated
+{{else}}
+{{#if scss}}
+├── scss/
+│   └── style.scss
+├── css/
+│   └── style.css     # generated
+{{else}}
 ├── css/
 │   └── style.css
+{{/if}}
+{{/if}}
 └── js/
     └── main.js
 ```
 
 ## Customization
 
-1. Edit `index.html` to update your information
+1. Edit `index.html` to update your information, or regenerate with `webgen new --profile`
+{{#if tailwind}}
+2. Style with Tailwind utility classes; theme colors live in `tailwind.config.js`
+{{else}}
+{{#if scss}}
+2. Modify `scss/style.scss` for styling
+{{else}}
 2. Modify `css/style.css` for styling
+{{/if}}
+{{/if}}
 3. Update `js/main.js` for behavior
 
 ## Deploy
@@ -552,11 +806,131 @@ Deploy to:
 - Vercel
 - Any static hosting
 
+{{#if tailwind}}
+Run `{{run_cmd}} build` before deploying.
+{{else}}
+{{#if scss}}
+Run `{{run_cmd}} build` before deploying.
+{{else}}
 No build required!
+{{/if}}
+{{/if}}
 
 ## License
 
 MIT
 "##;
 
-const GITIGNORE: &str = ".DS_Store\nThumbs.db\n*.log\n";
+const DOCKERFILE: &str = r##"FROM nginx:1.27-alpine
+COPY nginx.conf /etc/nginx/conf.d/default.conf
+COPY index.html /usr/share/nginx/html/
+COPY css /usr/share/nginx/html/css
+COPY js /usr/share/nginx/html/js
+EXPOSE 80
+"##;
+
+const DOCKERFILE_BUILD: &str = r##"FROM node:{{node_version}}-alpine AS build
+WORKDIR /app
+{{docker_install}}
+COPY . .
+RUN {{run_cmd}} build
+
+FROM nginx:1.27-alpine
+COPY nginx.conf /etc/nginx/conf.d/default.conf
+COPY --from=build /app/index.html /usr/share/nginx/html/
+COPY --from=build /app/css /usr/share/nginx/html/css
+COPY --from=build /app/js /usr/share/nginx/html/js
+EXPOSE 80
+"##;
+
+const GITIGNORE: &str = r##".DS_Store
+Thumbs.db
+*.log
+{{#if tailwind}}
+node_modules/
+css/style.css
+{{/if}}
+{{#if scss}}
+node_modules/
+css/style.css
+{{/if}}
+"##;
+
+const PACKAGE_JSON: &str = r##"{
+  "name": "{{package_name}}",
+  "version": "0.1.0",
+  "private": true,
+{{#if package_manager_spec}}
+  "packageManager": "{{package_manager_spec}}",
+{{/if}}
+  "scripts": {
+{{#if tailwind}}
+    "build": "tailwindcss -i ./src/input.css -o ./css/style.css --minify",
+    "watch": "tailwindcss -i ./src/input.css -o ./css/style.css --watch"
+{{else}}
+    "build": "sass scss/style.scss css/style.css --no-source-map",
+    "watch": "sass --watch scss/style.scss css/style.css"
+{{/if}}
+  },
+  "devDependencies": {
+{{#if tailwind}}
+    "tailwindcss": "^3.4.0"
+{{else}}
+    "sass": "^1.69.5"
+{{/if}}
+  }
+}
+"##;
+
+const TAILWIND_CONFIG: &str = r##"/** @type {import('tailwindcss').Config} */
+module.exports = {
+  content: ['./index.html', './js/**/*.js'],
+  theme: {
+    extend: {
+      colors: {
+        primary: '#6366f1',
+        secondary: '#8b5cf6',
+      },
+      keyframes: {
+        'fade-in-up': {
+          from: { opacity: '0', transform: 'translateY(30px)' },
+          to: { opacity: '1', transform: 'translateY(0)' },
+        },
+      },
+      animation: {
+        'fade-in-up': 'fade-in-up 0.8s ease-out both',
+      },
+    },
+  },
+  plugins: [],
+};
+"##;
+
+const TAILWIND_CSS: &str = r##"@tailwind base;
+@tailwind components;
+@tailwind utilities;
+
+@layer base {
+  html {
+    scroll-behavior: smooth;
+  }
+}
+
+@layer components {
+  .btn {
+    @apply inline-block cursor-pointer rounded-md px-7 py-3 font-medium transition;
+  }
+
+  .btn-primary {
+    @apply bg-primary text-white hover:-translate-y-0.5 hover:shadow-lg;
+  }
+
+  .btn-secondary {
+    @apply border-2 border-white text-white hover:bg-white hover:text-primary;
+  }
+
+  .section-title {
+    @apply mb-12 text-center text-4xl font-bold text-slate-800;
+  }
+}
+"##;
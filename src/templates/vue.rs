@@ -1,30 +1,161 @@
-use std::path::Path;
-use anyhow::Result;
-use crate::generator::write_file;
-
-pub fn generate(project_path: &Path) -> Result<()> {
-    write_file(&project_path.join("package.json"), PACKAGE_JSON)?;
-    write_file(&project_path.join("vite.config.js"), VITE_CONFIG)?;
-    write_file(&project_path.join("index.html"), INDEX_HTML)?;
-    write_file(&project_path.join("src/main.js"), MAIN_JS)?;
-    write_file(&project_path.join("src/App.vue"), APP_VUE)?;
-    write_file(&project_path.join("src/style.css"), STYLE_CSS)?;
-    write_file(&project_path.join("src/components/Hero.vue"), HERO_VUE)?;
-    write_file(&project_path.join("src/components/About.vue"), ABOUT_VUE)?;
-    write_file(&project_path.join("src/components/Projects.vue"), PROJECTS_VUE)?;
-    write_file(&project_path.join("README.md"), README)?;
-    write_file(&project_path.join(".gitignore"), GITIGNORE)?;
-    
-    Ok(())
+use serde_json::{json, Map, Value};
+
+use crate::render::Variables;
+use crate::templates::{self, Feature, Template, TemplateFile};
+
+pub struct Vue;
+
+impl Template for Vue {
+    fn id(&self) -> &str {
+        "vue"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["vue-portfolio"]
+    }
+
+    fn display_name(&self) -> &str {
+        "Vue Portfolio"
+    }
+
+    fn description(&self) -> &str {
+        "Vue.js 3 portfolio with Composition API"
+    }
+
+    fn features(&self) -> &[Feature] {
+        &[
+            Feature::TypeScript,
+            Feature::Css,
+            Feature::Profile,
+            Feature::PackageManager,
+            Feature::Docker,
+            Feature::Ci,
+            Feature::Tests,
+        ]
+    }
+
+    fn variables(&self) -> Map<String, Value> {
+        let profile = json!({
+            "title": "Full Stack Developer | Designer | Creator",
+            "skills": [
+                { "name": "Frontend", "description": "HTML, CSS, JavaScript, Vue, React" },
+                { "name": "Backend", "description": "Node.js, Python, Flask, Express" },
+                { "name": "Database", "description": "MongoDB, PostgreSQL, MySQL" },
+                { "name": "Tools", "description": "Git, Docker, AWS, CI/CD" },
+            ],
+            "projects": [
+                {
+                    "name": "Project 1",
+                    "description": "A cool project built with Vue",
+                    "tech": ["Vue", "Node.js", "MongoDB"],
+                    "url": "https://github.com/yourusername/project1",
+                },
+                {
+                    "name": "Project 2",
+                    "description": "An awesome web application",
+                    "tech": ["Python", "Flask", "PostgreSQL"],
+                    "url": "https://github.com/yourusername/project2",
+                },
+                {
+                    "name": "Project 3",
+                    "description": "A modern mobile app",
+                    "tech": ["Vue", "Firebase"],
+                    "url": "https://github.com/yourusername/project3",
+                },
+            ],
+        });
+        Map::from_iter([("profile".to_string(), profile)])
+    }
+
+    fn files(&self, vars: &Variables) -> Vec<TemplateFile> {
+        let typescript = vars.flag("typescript");
+        let tailwind = vars.flag("tailwind");
+        let ext = if typescript { "ts" } else { "js" };
+        let css_ext = if vars.flag("scss") { "scss" } else { "css" };
+
+        let mut files = vec![
+            TemplateFile::new("package.json", PACKAGE_JSON),
+            TemplateFile::new(format!("vite.config.{}", ext), VITE_CONFIG),
+            TemplateFile::new("index.html", INDEX_HTML),
+            TemplateFile::new(format!("src/main.{}", ext), MAIN_JS),
+            TemplateFile::new("src/App.vue", APP_VUE),
+            TemplateFile::new(
+                format!("src/style.{}", css_ext),
+                if tailwind { TAILWIND_CSS } else { STYLE_CSS },
+            ),
+            TemplateFile::new("src/components/Hero.vue", HERO_VUE),
+            TemplateFile::new("src/components/About.vue", ABOUT_VUE),
+            TemplateFile::new("src/components/Projects.vue", PROJECTS_VUE),
+            TemplateFile::new("README.md", README),
+            TemplateFile::new(".gitignore", GITIGNORE),
+        ];
+
+        if typescript {
+            files.push(TemplateFile::new("tsconfig.json", TSCONFIG));
+            files.push(TemplateFile::new("tsconfig.node.json", TSCONFIG_NODE));
+            files.push(TemplateFile::new("src/env.d.ts", ENV_D_TS));
+        }
+        if vars.flag("profile.work") {
+            files.push(TemplateFile::new("src/components/Experience.vue", EXPERIENCE_VUE));
+        }
+        if vars.flag("profile.contact") {
+            files.push(TemplateFile::new("src/components/Contact.vue", CONTACT_VUE));
+        }
+        if tailwind {
+            files.push(TemplateFile::new("tailwind.config.js", TAILWIND_CONFIG));
+            files.push(TemplateFile::new("postcss.config.js", POSTCSS_CONFIG));
+        }
+        if vars.flag("docker") {
+            files.push(TemplateFile::new("Dockerfile", DOCKERFILE));
+            files.push(TemplateFile::new("nginx.conf", templates::NGINX_CONF));
+            files.push(TemplateFile::new(".dockerignore", templates::NODE_DOCKERIGNORE));
+        }
+        if vars.flag("tests") {
+            files.push(TemplateFile::new(format!("src/App.test.{}", ext), APP_TEST));
+            files.push(TemplateFile::new(format!("src/components/About.test.{}", ext), ABOUT_TEST));
+            files.push(TemplateFile::new(
+                format!("src/components/Projects.test.{}", ext),
+                PROJECTS_TEST,
+            ));
+        }
+        if vars.flag("ci") {
+            files.push(TemplateFile::new(".eslintrc.cjs", ESLINTRC));
+        }
+        files.extend(templates::package_manager_files(vars));
+        files.extend(templates::ci_files(vars, GITHUB_CI, GITLAB_CI));
+
+        files
+    }
+
+    fn next_steps(&self, vars: &Variables) -> Vec<String> {
+        let mut steps = Vec::new();
+        if !vars.flag("installed") {
+            steps.push(vars.text("install_cmd").to_string());
+        }
+        steps.push(format!("{} dev", vars.text("run_cmd")));
+        steps
+    }
 }
 
 const PACKAGE_JSON: &str = r###"{
-  "name": "vue-portfolio",
+  "name": "{{package_name}}",
   "version": "0.1.0",
+{{#if description}}
+  "description": {{description | json}},
+{{/if}}
   "private": true,
+{{#if package_manager_spec}}
+  "packageManager": "{{package_manager_spec}}",
+{{/if}}
   "scripts": {
     "dev": "vite",
-    "build": "vite build",
+    "build": "{{#if typescript}}vue-tsc && {{/if}}vite build",
+{{#if ci}}
+    "lint": "eslint --ext .js,.vue{{#if typescript}},.ts{{/if}} src",
+{{/if}}
+{{#if tests}}
+    "test": "vitest",
+{{/if}}
     "preview": "vite preview"
   },
   "dependencies": {
@@ -32,16 +163,50 @@ const PACKAGE_JSON: &str = r###"{
   },
   "devDependencies": {
     "@vitejs/plugin-vue": "^4.5.0",
-    "vite": "^5.0.0"
+{{#if tests}}
+    "@vue/test-utils": "^2.4.6",
+    "jsdom": "^24.1.0",
+{{/if}}
+{{#if ci}}
+{{#if typescript}}
+    "@vue/eslint-config-typescript": "^13.0.0",
+{{/if}}
+    "eslint": "^8.57.0",
+    "eslint-plugin-vue": "^9.28.0",
+{{/if}}
+{{#if tailwind}}
+    "autoprefixer": "^10.4.16",
+    "postcss": "^8.4.31",
+{{/if}}
+{{#if scss}}
+    "sass": "^1.69.5",
+{{/if}}
+{{#if tailwind}}
+    "tailwindcss": "^3.4.0",
+{{/if}}
+{{#if typescript}}
+    "typescript": "^5.2.2",
+{{/if}}
+    "vite": "^5.0.0"{{#if tests}},
+    "vitest": "^1.6.0"{{/if}}{{#if typescript}},
+    "vue-tsc": "^1.8.22"{{/if}}
   }
 }
 "###;
 
-const VITE_CONFIG: &str = r###"import { defineConfig } from 'vite'
+const VITE_CONFIG: &str = r###"{{#if tests}}
+/// <reference types="vitest" />
+{{/if}}
+import { defineConfig } from 'vite'
 import vue from '@vitejs/plugin-vue'
 
 export default defineConfig({
   plugins: [vue()],
+{{#if tests}}
+  test: {
+    environment: 'jsdom',
+  },
+{{/if}}
 })
 "###;
 
@@ -51,60 +216,102 @@ const INDEX_HTML: &str = r###"<!DOCTYPE html>
     <meta charset="UTF-8">
     <link rel="icon" type="image/svg+xml" href="/vite.svg">
     <meta name="viewport" content="width=device-width, initial-scale=1.0">
-    <title>Vue Portfolio</title>
+{{#if description}}
+    <meta name="description" content="{{description | html}}">
+{{/if}}
+    <title>{{project_name | html}}</title>
   </head>
   <body>
     <div id="app"></div>
-    <script type="module" src="/src/main.js"></script>
+    <script type="module" src="/src/main.{{#if typescript}}ts{{else}}js{{/if}}"></script>
   </body>
 </html>
 "###;
 
 const MAIN_JS: &str = r###"import { createApp } from 'vue'
-import './style.css'
+import './style.{{css_ext}}'
 import App from './App.vue'
 
 createApp(App).mount('#app')
 "###;
 
 const APP_VUE: &str = r###"<template>
-  <div id="app">
-    <nav class="navbar">
-      <div class="container">
-        <div class="logo">My Portfolio</div>
+  <div id="app"{{#if tailwind}} class="min-h-screen bg-slate-50 font-sans text-slate-800 antialiased"{{/if}}>
+    <nav class="{{#if tailwind}}sticky top-0 z-50 bg-white shadow-md{{else}}navbar{{/if}}">
+      <div class="{{#if tailwind}}mx-auto flex max-w-6xl items-center justify-between px-5 py-4{{else}}container{{/if}}">
+        <div class="{{#if tailwind}}text-2xl font-bold text-primary{{else}}logo{{/if}}">{{project_name | html}}</div>
+{{#if tailwind}}
+        <ul class="flex gap-4 md:gap-8">
+          <li><a href="#hero" class="transition-colors hover:text-primary">Home</a></li>
+          <li><a href="#about" class="transition-colors hover:text-primary">About</a></li>
+{{#if profile.work}}
+          <li><a href="#experience" class="transition-colors hover:text-primary">Experience</a></li>
+{{/if}}
+          <li><a href="#projects" class="transition-colors hover:text-primary">Projects</a></li>
+{{#if profile.contact}}
+          <li><a href="#contact" class="transition-colors hover:text-primary">Contact</a></li>
+{{/if}}
+        </ul>
+{{else}}
         <ul class="nav-menu">
           <li><a href="#hero">Home</a></li>
           <li><a href="#about">About</a></li>
+{{#if profile.work}}
+          <li><a href="#experience">Experience</a></li>
+{{/if}}
           <li><a href="#projects">Projects</a></li>
+{{#if profile.contact}}
+          <li><a href="#contact">Contact</a></li>
+{{/if}}
         </ul>
+{{/if}}
       </div>
     </nav>
 
     <Hero />
     <About />
+{{#if profile.work}}
+    <Experience />
+{{/if}}
     <Projects />
+{{#if profile.contact}}
+    <Contact />
+{{/if}}
 
-    <footer class="footer">
-      <div class="container">
-        <p>&copy; 2024 My Portfolio. Built with Vue.js</p>
+    <footer class="{{#if tailwind}}bg-secondary py-8 text-center text-white{{else}}footer{{/if}}">
+      <div class="{{#if tailwind}}mx-auto max-w-6xl px-5{{else}}container{{/if}}">
+        <p>&copy; {{year}} {{author | html}}. Built with Vue.js</p>
       </div>
     </footer>
   </div>
 </template>
 
-<script>
+<script{{#if typescript}} lang="ts"{{/if}}>
+{{#if typescript}}
+import { defineComponent } from 'vue'
+{{/if}}
 import Hero from './components/Hero.vue'
 import About from './components/About.vue'
+{{#if profile.work}}
+import Experience from './components/Experience.vue'
+{{/if}}
 import Projects from './components/Projects.vue'
+{{#if profile.contact}}
+import Contact from './components/Contact.vue'
+{{/if}}
 
-export default {
+export default {{#if typescript}}defineComponent({{/if}}{
   name: 'App',
   components: {
     Hero,
     About,
-    Projects
+{{#if profile.work}}
+    Experience,
+{{/if}}
+    Projects{{#if profile.contact}},
+    Contact{{/if}}
   }
-}
+}{{#if typescript}}){{/if}}
 </script>
 "###;
 
@@ -313,6 +520,87 @@ body {
   font-size: 0.85rem;
 }
 
+/* Experience */
+.experience {
+  padding: 80px 20px;
+}
+
+.experience h2 {
+  text-align: center;
+  font-size: 2.5rem;
+  margin-bottom: 3rem;
+}
+
+.timeline {
+  max-width: 800px;
+  margin: 0 auto;
+  padding-left: 2rem;
+  border-left: 3px solid var(--primary);
+  list-style: none;
+}
+
+.timeline-item {
+  position: relative;
+  margin-bottom: 2.5rem;
+}
+
+.timeline-item::before {
+  content: '';
+  position: absolute;
+  left: calc(-2rem - 8px);
+  top: 0.4rem;
+  width: 13px;
+  height: 13px;
+  border-radius: 50%;
+  background: var(--primary);
+}
+
+.timeline-item h3 {
+  color: var(--primary);
+}
+
+.timeline-period {
+  color: var(--gray);
+  font-size: 0.9rem;
+  margin-bottom: 0.5rem;
+}
+
+.timeline-item ul {
+  margin-top: 0.5rem;
+  padding-left: 1.25rem;
+}
+
+.about-bio {
+  max-width: 800px;
+  margin: 0 auto 3rem;
+  text-align: center;
+  font-size: 1.1rem;
+}
+
+/* Contact */
+.contact {
+  padding: 80px 20px;
+  text-align: center;
+}
+
+.contact h2 {
+  font-size: 2.5rem;
+  margin-bottom: 2rem;
+}
+
+.contact a {
+  color: var(--primary);
+  font-weight: 500;
+  text-decoration: none;
+}
+
+.social-links {
+  display: flex;
+  justify-content: center;
+  gap: 2rem;
+  margin-top: 1.5rem;
+}
+
 /* Footer */
 .footer {
   background: var(--secondary);
@@ -333,32 +621,39 @@ body {
 "###;
 
 const HERO_VUE: &str = r###"<template>
-  <section class="hero" id="hero">
-    <div class="container">
-      <h1>Hi, I'm Your Name</h1>
-      <p>Full Stack Developer | Designer | Creator</p>
-      <div class="hero-buttons">
-        <a href="#projects" class="btn btn-primary">View Projects</a>
+  <section class="{{#if tailwind}}bg-gradient-to-br from-primary to-secondary px-5 py-24 text-center text-white{{else}}hero{{/if}}" id="hero">
+    <div class="{{#if tailwind}}mx-auto max-w-6xl px-5{{else}}container{{/if}}">
+      <h1{{#if tailwind}} class="mb-4 text-4xl font-bold md:text-5xl"{{/if}}>Hi, I'm {{author | html}}</h1>
+      <p{{#if tailwind}} class="mb-8 text-xl opacity-90 md:text-2xl"{{/if}}>{{profile.title | html}}</p>
+      <div class="{{#if tailwind}}flex justify-center gap-4{{else}}hero-buttons{{/if}}">
+        <a href="#projects" class="btn {{#if tailwind}}bg-white text-primary hover:-translate-y-0.5 hover:shadow-lg{{else}}btn-primary{{/if}}">View Projects</a>
         <a href="#about" class="btn btn-secondary">Learn More</a>
       </div>
     </div>
   </section>
 </template>
 
-<script>
-export default {
+<script{{#if typescript}} lang="ts"{{/if}}>
+{{#if typescript}}
+import { defineComponent } from 'vue'
+
+{{/if}}
+export default {{#if typescript}}defineComponent({{/if}}{
   name: 'Hero'
-}
+}{{#if typescript}}){{/if}}
 </script>
 "###;
 
 const ABOUT_VUE: &str = r###"<template>
-  <section class="about" id="about">
-    <div class="container">
-      <h2>About Me</h2>
-      <div class="skills-grid">
-        <div v-for="skill in skills" :key="skill.title" class="skill-card">
-          <h3>{{ skill.title }}</h3>
+  <section class="{{#if tailwind}}px-5 py-20{{else}}about{{/if}}" id="about">
+    <div class="{{#if tailwind}}mx-auto max-w-6xl px-5{{else}}container{{/if}}">
+      <h2{{#if tailwind}} class="mb-12 text-center text-4xl font-bold"{{/if}}>About Me</h2>
+{{#if profile.bio}}
+      <p class="{{#if tailwind}}mx-auto mb-12 max-w-3xl text-center text-lg{{else}}about-bio{{/if}}">{{profile.bio | html}}</p>
+{{/if}}
+      <div class="{{#if tailwind}}grid gap-8 sm:grid-cols-2 lg:grid-cols-4{{else}}skills-grid{{/if}}">
+        <div v-for="skill in skills" :key="skill.title" class="{{#if tailwind}}rounded-xl bg-white p-8 shadow-lg transition-transform hover:-translate-y-1{{else}}skill-card{{/if}}">
+          <h3{{#if tailwind}} class="mb-4 text-lg font-semibold text-primary"{{/if}}>{{ skill.title }}</h3>
           <p>{{ skill.description }}</p>
         </div>
       </div>
@@ -366,33 +661,96 @@ const ABOUT_VUE: &str = r###"<template>
   </section>
 </template>
 
-<script>
-export default {
+<script{{#if typescript}} lang="ts"{{/if}}>
+{{#if typescript}}
+import { defineComponent } from 'vue'
+
+interface Skill {
+  title: string
+  description: string
+}
+
+{{/if}}
+export default {{#if typescript}}defineComponent({{/if}}{
   name: 'About',
   data() {
     return {
       skills: [
-        { title: 'Frontend', description: 'HTML, CSS, JavaScript, Vue, React' },
-        { title: 'Backend', description: 'Node.js, Python, Flask, Express' },
-        { title: 'Database', description: 'MongoDB, PostgreSQL, MySQL' },
-        { title: 'Tools', description: 'Git, Docker, AWS, CI/CD' }
-      ]
+{{#each profile.skills}}
+        { title: {{name | quote}}, description: {{description | quote}} }{{#unless @last}},{{/unless}}
+{{/each}}
+      ]{{#if typescript}} as Skill[]{{/if}}
     }
   }
+}{{#if typescript}}){{/if}}
+</script>
+"###;
+
+const EXPERIENCE_VUE: &str = r###"<template>
+  <section class="{{#if tailwind}}px-5 py-20{{else}}experience{{/if}}" id="experience">
+    <div class="{{#if tailwind}}mx-auto max-w-6xl px-5{{else}}container{{/if}}">
+      <h2{{#if tailwind}} class="mb-12 text-center text-4xl font-bold"{{/if}}>Experience</h2>
+      <ol class="{{#if tailwind}}mx-auto max-w-3xl border-l-4 border-primary pl-8{{else}}timeline{{/if}}">
+        <li v-for="(job, index) in jobs" :key="index" class="{{#if tailwind}}relative mb-10{{else}}timeline-item{{/if}}">
+{{#if tailwind}}
+          <span class="absolute -left-[2.55rem] top-1.5 h-4 w-4 rounded-full bg-primary"></span>
+{{/if}}
+          <h3{{#if tailwind}} class="text-lg font-semibold text-primary"{{/if}}>{{ [job.position, job.company].filter(Boolean).join(' · ') }}</h3>
+          <p v-if="job.period" class="{{#if tailwind}}mb-2 text-sm text-slate-500{{else}}timeline-period{{/if}}">{{ job.period }}</p>
+          <p v-if="job.summary">{{ job.summary }}</p>
+          <ul v-if="job.highlights.length"{{#if tailwind}} class="mt-2 list-disc pl-5"{{/if}}>
+            <li v-for="highlight in job.highlights" :key="highlight">{{ highlight }}</li>
+          </ul>
+        </li>
+      </ol>
+    </div>
+  </section>
+</template>
+
+<script{{#if typescript}} lang="ts"{{/if}}>
+{{#if typescript}}
+import { defineComponent } from 'vue'
+
+interface Job {
+  company: string
+  position: string
+  period: string
+  summary: string
+  highlights: string[]
 }
+
+{{/if}}
+export default {{#if typescript}}defineComponent({{/if}}{
+  name: 'Experience',
+  data() {
+    return {
+      jobs: [
+{{#each profile.work}}
+        {
+          company: {{company | quote}},
+          position: {{position | quote}},
+          period: {{period | quote}},
+          summary: {{#if summary}}{{summary | quote}}{{else}}''{{/if}},
+          highlights: [{{#each highlights}}{{this | quote}}{{#unless @last}}, {{/unless}}{{/each}}]
+        }{{#unless @last}},{{/unless}}
+{{/each}}
+      ]{{#if typescript}} as Job[]{{/if}}
+    }
+  }
+}{{#if typescript}}){{/if}}
 </script>
 "###;
 
 const PROJECTS_VUE: &str = r###"<template>
-  <section class="projects" id="projects">
-    <div class="container">
-      <h2>My Projects</h2>
-      <div class="projects-grid">
-        <div v-for="project in projects" :key="project.title" class="project-card">
-          <h3>{{ project.title }}</h3>
+  <section class="{{#if tailwind}}bg-white px-5 py-20{{else}}projects{{/if}}" id="projects">
+    <div class="{{#if tailwind}}mx-auto max-w-6xl px-5{{else}}container{{/if}}">
+      <h2{{#if tailwind}} class="mb-12 text-center text-4xl font-bold"{{/if}}>My Projects</h2>
+      <div class="{{#if tailwind}}grid gap-8 md:grid-cols-2 lg:grid-cols-3{{else}}projects-grid{{/if}}">
+        <div v-for="project in projects" :key="project.title" class="{{#if tailwind}}rounded-xl bg-slate-50 p-8 shadow-lg{{else}}project-card{{/if}}">
+          <h3{{#if tailwind}} class="mb-4 text-lg font-semibold text-primary"{{/if}}>{{ project.title }}</h3>
           <p>{{ project.description }}</p>
-          <div class="tech-tags">
-            <span v-for="tech in project.tech" :key="tech" class="tag">{{ tech }}</span>
+          <div class="{{#if tailwind}}my-4 flex flex-wrap gap-2{{else}}tech-tags{{/if}}">
+            <span v-for="tech in project.tech" :key="tech" class="{{#if tailwind}}rounded-full bg-white px-4 py-1 text-sm{{else}}tag{{/if}}">{{ tech }}</span>
           </div>
           <a :href="project.github" class="btn btn-primary" target="_blank">View on GitHub</a>
         </div>
@@ -401,45 +759,55 @@ const PROJECTS_VUE: &str = r###"<template>
   </section>
 </template>
 
-<script>
-export default {
+<script{{#if typescript}} lang="ts"{{/if}}>
+{{#if typescript}}
+import { defineComponent } from 'vue'
+
+interface Project {
+  title: string
+  description: string
+  tech: string[]
+  github: string
+}
+
+{{/if}}
+export default {{#if typescript}}defineComponent({{/if}}{
   name: 'Projects',
   data() {
     return {
       projects: [
+{{#each profile.projects}}
         {
-          title: 'Project 1',
-          description: 'A cool project built with Vue',
-          tech: ['Vue', 'Node.js', 'MongoDB'],
-          github: 'https://github.com/yourusername/project1'
-        },
-        {
-          title: 'Project 2',
-          description: 'An awesome web application',
-          tech: ['Python', 'Flask', 'PostgreSQL'],
-          github: 'https://github.com/yourusername/project2'
-        },
-        {
-          title: 'Project 3',
-          description: 'A modern mobile app',
-          tech: ['Vue', 'Firebase'],
-          github: 'https://github.com/yourusername/project3'
-        }
-      ]
+          title: {{name | quote}},
+          description: {{description | quote}},
+          tech: [{{#each tech}}{{this | quote}}{{#unless @last}}, {{/unless}}{{/each}}],
+          github: {{url | quote}}
+        }{{#unless @last}},{{/unless}}
+{{/each}}
+      ]{{#if typescript}} as Project[]{{/if}}
     }
   }
-}
+}{{#if typescript}}){{/if}}
 </script>
 "###;
 
-const README: &str = r###"# Vue Portfolio
+const README: &str = r###"# {{project_name}}
 
-A modern, responsive portfolio website built with Vue.js 3 and Vite.
+{{#if description}}{{description}}{{else}}A modern, responsive portfolio website built with Vue.js 3 and Vite.{{/if}}
 
 ## Features
 
 - ⚡ Vue 3 with Composition API
 - 🛠️ Vite for fast development
+{{#if tailwind}}
+- 🌊 Tailwind CSS
+{{/if}}
+{{#if scss}}
+- 🎨 SCSS
+{{/if}}
+{{#if typescript}}
+- 🔷 TypeScript
+{{/if}}
 - 🏠 Hero section
 - 👤 About section with skills
 - 🚀 Projects showcase
@@ -449,32 +817,65 @@ A modern, responsive portfolio website built with Vue.js 3 and Vite.
 
 ### Install dependencies
 ```bash
-npm install
+{{install_cmd}}
 ```
 
 ### Run development server
 ```bash
-npm run dev
+{{run_cmd}} dev
 ```
 
 Open [http://localhost:5173](http://localhost:5173) to view it in your browser.
 
 ### Build for production
 ```bash
-npm run build
+{{run_cmd}} build
 ```
 
 ### Preview production build
 ```bash
-npm run preview
+{{run_cmd}} preview
 ```
+{{#if tests}}
 
+### Run tests
+```bash
+{{run_cmd}} test
+```
+
+Vitest and Vue Test Utils run the `*.test.{{#if typescript}}ts{{else}}js{{/if}}` files next to the components, in watch mode locally and once under CI.
+{{/if}}
+
+{{#if docker}}
+## Docker
+
+The image builds the app and serves `dist/` with nginx:
+
+```bash
+docker build -t {{package_name}} .
+docker run --rm -p 5173:80 {{package_name}}
+```
+
+Open [http://localhost:5173](http://localhost:5173).
+
+{{/if}}
 ## Customization
 
-- Edit components in `src/components/` to modify content
-- Modify `src/style.css` to change styling
+- Edit components in `src/components/` to modify content, or regenerate with `webgen new --profile`
+{{#if tailwind}}
+- Style with Tailwind utility classes; theme colors live in `tailwind.config.js`
+{{else}}
+- Modify `src/style.{{css_ext}}` to change styling
+{{/if}}
 - Update data in component's `data()` functions
 
+{{#if ci}}
+## Continuous Integration
+
+Every push and pull request installs dependencies, lints{{#if tests}}, runs the tests{{/if}} and builds the app.
+Commit `{{lockfile}}` after your first install: CI caches dependencies by it.
+
+{{/if}}
 ## Deploy
 
 This app can be deployed to:
@@ -488,6 +889,155 @@ This app can be deployed to:
 MIT
 "###;
 
+const DOCKERFILE: &str = r###"FROM node:{{node_version}}-alpine AS build
+WORKDIR /app
+{{docker_install}}
+COPY . .
+RUN {{run_cmd}} build
+
+FROM nginx:1.27-alpine
+COPY nginx.conf /etc/nginx/conf.d/default.conf
+COPY --from=build /app/dist /usr/share/nginx/html
+EXPOSE 80
+"###;
+
+const APP_TEST: &str = r###"import { describe, expect, it } from 'vitest'
+import { mount } from '@vue/test-utils'
+import App from './App.vue'
+
+describe('App', () => {
+  it('renders every section', () => {
+    const wrapper = mount(App)
+
+    expect(wrapper.find('h1').text()).toContain("Hi, I'm")
+    for (const id of ['hero', 'about', 'projects']) {
+      expect(wrapper.find(`#${id}`).exists()).toBe(true)
+    }
+  })
+})
+"###;
+
+const ABOUT_TEST: &str = r###"import { describe, expect, it } from 'vitest'
+import { mount } from '@vue/test-utils'
+import About from './About.vue'
+
+describe('About', () => {
+  it('lists every skill', () => {
+    const wrapper = mount(About)
+
+    expect(wrapper.findAll('h3').map((heading) => heading.text())).toEqual([
+{{#each profile.skills}}
+      {{name | quote}},
+{{/each}}
+    ])
+  })
+})
+"###;
+
+const PROJECTS_TEST: &str = r###"import { describe, expect, it } from 'vitest'
+import { mount } from '@vue/test-utils'
+import Projects from './Projects.vue'
+
+describe('Projects', () => {
+  it('shows a card for every project', () => {
+    const wrapper = mount(Projects)
+
+    expect(wrapper.findAll('h3').map((heading) => heading.text())).toEqual([
+{{#each profile.projects}}
+      {{name | quote}},
+{{/each}}
+    ])
+  })
+})
+"###;
+
+const ESLINTRC: &str = r###"/* eslint-env node */
+module.exports = {
+  root: true,
+  env: {
+    browser: true,
+    es2022: true,
+  },
+  extends: [
+    'eslint:recommended',
+    'plugin:vue/vue3-recommended',
+{{#if typescript}}
+    '@vue/eslint-config-typescript',
+{{/if}}
+  ],
+  parserOptions: {
+    ecmaVersion: 'latest',
+    sourceType: 'module',
+  },
+  rules: {
+    // Section components are deliberately named Hero, About, Projects, ...
+    'vue/multi-word-component-names': 'off',
+  },
+}
+"###;
+
+const GITHUB_CI: &str = r###"name: CI
+
+on:
+  push:
+    branches: [main, master]
+  pull_request:
+
+jobs:
+  build:
+    runs-on: ubuntu-latest
+    steps:
+      - uses: actions/checkout@v4
+{{#if github_setup}}
+      - {{github_setup}}
+{{/if}}
+      - uses: actions/setup-node@v4
+        with:
+          node-version: "{{node_version}}"
+{{#if github_cache}}
+          cache: {{github_cache}}
+{{/if}}
+      - name: Install dependencies
+        run: {{install_cmd}}
+      - name: Lint
+        run: {{run_cmd}} lint
+{{#if tests}}
+      - name: Test
+        run: {{run_cmd}} test
+{{/if}}
+      - name: Build
+        run: {{run_cmd}} build
+"###;
+
+const GITLAB_CI: &str = r###"image: node:{{node_version}}
+
+variables:
+  COREPACK_ENABLE_DOWNLOAD_PROMPT: "0"
+
+cache:
+  key:
+    files:
+      - {{lockfile}}
+  paths:
+    - node_modules/
+
+build:
+  before_script:
+{{#if setup_cmd}}
+    - {{setup_cmd}}
+{{/if}}
+    - {{install_cmd}}
+  script:
+    - {{run_cmd}} lint
+{{#if tests}}
+    - {{run_cmd}} test
+{{/if}}
+    - {{run_cmd}} build
+  artifacts:
+    paths:
+      - dist/
+"###;
+
 const GITIGNORE: &str = r###"# Logs
 logs
 *.log
@@ -515,3 +1065,118 @@ dist-ssr
 "###;
 
 
+
+const TSCONFIG: &str = r###"{
+  "compilerOptions": {
+    "target": "ES2020",
+    "useDefineForClassFields": true,
+    "module": "ESNext",
+    "lib": ["ES2020", "DOM", "DOM.Iterable"],
+    "skipLibCheck": true,
+    "moduleResolution": "bundler",
+    "allowImportingTsExtensions": true,
+    "resolveJsonModule": true,
+    "isolatedModules": true,
+    "noEmit": true,
+    "jsx": "preserve",
+    "strict": true,
+    "noUnusedLocals": true,
+    "noUnusedParameters": true,
+    "noFallthroughCasesInSwitch": true
+  },
+  "include": ["src/**/*.ts", "src/**/*.tsx", "src/**/*.vue"],
+  "references": [{ "path": "./tsconfig.node.json" }]
+}
+"###;
+
+const TSCONFIG_NODE: &str = r###"{
+  "compilerOptions": {
+    "composite": true,
+    "skipLibCheck": true,
+    "module": "ESNext",
+    "moduleResolution": "bundler",
+    "allowSyntheticDefaultImports": true
+  },
+  "include": ["vite.config.ts"]
+}
+"###;
+
+const ENV_D_TS: &str = r###"/// <reference types="vite/client" />
+
+declare module '*.vue' {
+  import type { DefineComponent } from 'vue'
+  const component: DefineComponent<object, object, unknown>
+  export default component
+}
+"###;
+
+const TAILWIND_CSS: &str = r###"@tailwind base;
+@tailwind components;
+@tailwind utilities;
+
+@layer components {
+  .btn {
+    @apply inline-block cursor-pointer rounded-md px-7 py-3 font-medium transition;
+  }
+
+  .btn-primary {
+    @apply bg-primary text-white hover:-translate-y-0.5 hover:shadow-lg;
+  }
+
+  .btn-secondary {
+    @apply border-2 border-white text-white hover:bg-white hover:text-primary;
+  }
+}
+"###;
+
+const TAILWIND_CONFIG: &str = r###"/** @type {import('tailwindcss').Config} */
+module.exports = {
+  content: ['./index.html', './src/**/*.{vue,js,ts}'],
+  theme: {
+    extend: {
+      colors: {
+        primary: '#42b883',
+        secondary: '#35495e',
+      },
+    },
+  },
+  plugins: [],
+};
+"###;
+
+const POSTCSS_CONFIG: &str = r###"module.exports = {
+  plugins: {
+    tailwindcss: {},
+    autoprefixer: {},
+  },
+};
+"###;
+
+const CONTACT_VUE: &str = r###"<template>
+  <section class="{{#if tailwind}}px-5 py-20 text-center{{else}}contact{{/if}}" id="contact">
+    <div class="{{#if tailwind}}mx-auto max-w-6xl px-5{{else}}container{{/if}}">
+      <h2{{#if tailwind}} class="mb-8 text-4xl font-bold"{{/if}}>Get In Touch</h2>
+{{#if profile.email}}
+      <p>Email me at <a href="mailto:{{profile.email | html}}"{{#if tailwind}} class="font-medium text-primary"{{/if}}>{{profile.email | html}}</a></p>
+{{/if}}
+{{#if profile.social}}
+      <div class="{{#if tailwind}}mt-6 flex justify-center gap-8 font-medium text-primary{{else}}social-links{{/if}}">
+{{#each profile.social}}
+        <a href="{{url | html}}" target="_blank" rel="noopener noreferrer">{{network | html}}</a>
+{{/each}}
+      </div>
+{{/if}}
+    </div>
+  </section>
+</template>
+
+<script{{#if typescript}} lang="ts"{{/if}}>
+{{#if typescript}}
+import { defineComponent } from 'vue'
+
+{{/if}}
+export default {{#if typescript}}defineComponent({{/if}}{
+  name: 'Contact'
+}{{#if typescript}}){{/if}}
+</script>
+"###;
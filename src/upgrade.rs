@@ -0,0 +1,203 @@
+//! `webgen upgrade`: regenerates a project's template with this version of
+//! webgen and merges the result into the files on disk.
+//!
+//! Files still as generated (their hash matches the manifest) are replaced.
+//! Customised files get a three-way merge whose base is the generated version,
+//! looked up by hash in the manifest's `bases` (kept by the previous upgrade)
+//! or the project's git history; without it, the whole file becomes a conflict.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use serde::Serialize;
+
+use crate::git;
+use crate::manifest::{self, Manifest};
+use crate::output::{self, Directory, Output};
+use crate::plan;
+use crate::templates::Template;
+
+#[derive(Default, Serialize)]
+pub struct UpgradeSummary {
+    /// Files the template generates now that it did not before.
+    pub added: Vec<String>,
+    /// Files that were still as generated.
+    pub updated: Vec<String>,
+    /// Customised files the template's changes merged into cleanly.
+    pub merged: Vec<String>,
+    /// Customised files left with conflict markers.
+    pub conflicts: Vec<String>,
+    /// Untouched files the template no longer generates.
+    pub removed: Vec<String>,
+    /// Files the template changed or dropped that were deleted or customised,
+    /// and are left as they are.
+    pub kept: Vec<String>,
+}
+
+impl UpgradeSummary {
+    pub fn is_empty(&self) -> bool {
+        [&self.added, &self.updated, &self.merged, &self.conflicts, &self.removed, &self.kept]
+            .iter()
+            .all(|paths| paths.is_empty())
+    }
+}
+
+/// What an upgrade will do, worked out before anything is written.
+pub struct Upgrade {
+    pub from_version: String,
+    pub summary: UpgradeSummary,
+    /// New contents by path; `None` deletes the file.
+    changes: Vec<(String, Option<String>)>,
+}
+
+impl Upgrade {
+    pub fn prepare(dir: &Path, template: &dyn Template, manifest: &Manifest) -> Result<Self> {
+        let plan = manifest.regenerate(template)?;
+
+        let mut upgrade = Self {
+            from_version: manifest.webgen_version.clone(),
+            summary: UpgradeSummary::default(),
+            changes: Vec::new(),
+        };
+        let summary = &mut upgrade.summary;
+        let changes = &mut upgrade.changes;
+        // Generated contents of the files that will still differ from them.
+        let mut bases = BTreeMap::new();
+
+        for file in plan.files.iter().filter(|f| f.path != manifest::FILE_NAME) {
+            let path = file.path.clone();
+            let current = read(&dir.join(&path))?;
+            let generated = manifest.files.get(&path);
+            let template_changed = generated != Some(&file.sha256);
+
+            let Some(current) = current else {
+                if generated.is_none() {
+                    summary.added.push(path.clone());
+                    changes.push((path, Some(file.contents.clone())));
+                } else if template_changed {
+                    summary.kept.push(path);
+                }
+                continue;
+            };
+            if current == file.contents.as_bytes() {
+                continue;
+            }
+            if manifest.is_untouched(&path, &current) {
+                summary.updated.push(path.clone());
+                changes.push((path, Some(file.contents.clone())));
+                continue;
+            }
+            bases.insert(path.clone(), file.contents.clone());
+            if !template_changed {
+                continue;
+            }
+
+            let Ok(current) = String::from_utf8(current) else {
+                summary.kept.push(path);
+                continue;
+            };
+            let base = generated.and_then(|hash| find_generated(dir, manifest, &path, hash));
+            match diffy::merge(base.as_deref().unwrap_or_default(), &current, &file.contents) {
+                Ok(merged) if merged == current => {}
+                Ok(merged) => {
+                    if merged == file.contents {
+                        bases.remove(&path);
+                    }
+                    summary.merged.push(path.clone());
+                    changes.push((path, Some(merged)));
+                }
+                Err(conflicted) => {
+                    summary.conflicts.push(path.clone());
+                    let marked = relabel(&conflicted, &manifest.webgen_version, base.is_some());
+                    changes.push((path, Some(marked)));
+                }
+            }
+        }
+
+        for (path, hash) in &manifest.files {
+            if plan.files.iter().any(|f| f.path == *path) {
+                continue;
+            }
+            output::check_path(path).context("Invalid file in the manifest")?;
+            match read(&dir.join(path))? {
+                Some(current) if plan::hash(&current) == *hash => {
+                    summary.removed.push(path.clone());
+                    changes.push((path.clone(), None));
+                }
+                Some(_) => summary.kept.push(path.clone()),
+                None => {}
+            }
+        }
+
+        let Some(generated) = plan.files.iter().find(|f| f.path == manifest::FILE_NAME) else {
+            bail!("The regenerated project has no {}", manifest::FILE_NAME);
+        };
+        let mut next: Manifest = serde_json::from_str(&generated.contents)?;
+        next.bases = bases;
+        let next = next.to_json();
+        if read(&dir.join(manifest::FILE_NAME))?.as_deref() != Some(next.as_bytes()) {
+            changes.push((manifest::FILE_NAME.to_string(), Some(next)));
+        }
+
+        Ok(upgrade)
+    }
+
+    pub fn apply(&self, dir: &Path) -> Result<()> {
+        let mut out = Directory::new(dir);
+        for (path, contents) in &self.changes {
+            match contents {
+                Some(contents) => out.write(path, contents),
+                None => fs::remove_file(dir.join(path)).map_err(Into::into),
+            }
+            .with_context(|| format!("Failed to update {}", path))?;
+        }
+        Ok(())
+    }
+}
+
+fn read(path: &Path) -> Result<Option<Vec<u8>>> {
+    match fs::read(path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e).with_context(|| format!("Failed to read {}", path.display())),
+    }
+}
+
+/// `path` as webgen generated it, i.e. with hash `hash`: kept in the manifest
+/// by the previous upgrade, or committed in the project's history.
+fn find_generated(dir: &Path, manifest: &Manifest, path: &str, hash: &str) -> Option<String> {
+    if let Some(base) = manifest.bases.get(path).filter(|base| plan::hash(base.as_bytes()) == hash) {
+        return Some(base.clone());
+    }
+    if !git::inside_work_tree(dir) {
+        return None;
+    }
+    git::revisions(dir, path)
+        .ok()?
+        .iter()
+        .filter_map(|rev| git::show(dir, rev, path).ok())
+        .find(|contents| plan::hash(contents) == hash)
+        .and_then(|contents| String::from_utf8(contents).ok())
+}
+
+/// Replaces diffy's "ours"/"original"/"theirs" conflict labels with ones that
+/// say where each side comes from.
+fn relabel(conflicted: &str, from_version: &str, found_base: bool) -> String {
+    let original = if found_base {
+        format!("generated by webgen {}", from_version)
+    } else {
+        format!("generated by webgen {} (not found in git history)", from_version)
+    };
+    conflicted
+        .split_inclusive('\n')
+        .map(|line| match line.trim_end_matches(['\r', '\n']) {
+            "<<<<<<< ours" => "<<<<<<< yours\n".to_string(),
+            "||||||| original" => format!("||||||| {}\n", original),
+            ">>>>>>> theirs" => format!(">>>>>>> webgen {}\n", crate::VERSION),
+            _ => line.to_string(),
+        })
+        .collect()
+}
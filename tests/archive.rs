@@ -0,0 +1,134 @@
+//! `webgen new --archive`, reading each archive format back.
+
+use std::env;
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+use flate2::read::GzDecoder;
+
+const NAME: &str = "site";
+
+/// An empty output folder for `case`.
+fn output_dir(case: &str) -> PathBuf {
+    let out = Path::new(env!("CARGO_TARGET_TMPDIR")).join("archive").join(case);
+    if out.exists() {
+        fs::remove_dir_all(&out).unwrap();
+    }
+    fs::create_dir_all(&out).unwrap();
+    out
+}
+
+fn webgen(out: &Path, args: &[&str]) -> Output {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_webgen"));
+    command
+        .current_dir(out)
+        .args(["new", NAME, "--template", "static", "--var", "year=2024", "--yes"])
+        .args(args)
+        .env("HOME", out)
+        .env("XDG_CONFIG_HOME", out)
+        .env("NO_COLOR", "1");
+    for (key, _) in env::vars_os() {
+        if key.to_string_lossy().starts_with("WEBGEN_") {
+            command.env_remove(key);
+        }
+    }
+    command.output().unwrap()
+}
+
+fn archive(out: &Path, archive: &str) {
+    let output = webgen(out, &["--archive", archive]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+}
+
+/// Everything in `dir` but `skip`, which should leave nothing of the project.
+fn entries(dir: &Path, skip: &str) -> Vec<String> {
+    fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+        .filter(|name| name != skip)
+        .collect()
+}
+
+/// (path, mode, contents) for every entry, with empty contents for folders.
+fn read_zip(path: &Path) -> Vec<(String, u32, String)> {
+    let mut zip = zip::ZipArchive::new(File::open(path).unwrap()).unwrap();
+    (0..zip.len())
+        .map(|i| {
+            let mut file = zip.by_index(i).unwrap();
+            let mut contents = String::new();
+            file.read_to_string(&mut contents).unwrap();
+            (file.name().to_string(), file.unix_mode().unwrap() & 0o777, contents)
+        })
+        .collect()
+}
+
+fn read_tar_gz(path: &Path) -> Vec<(String, u32, String)> {
+    let mut tar = tar::Archive::new(GzDecoder::new(File::open(path).unwrap()));
+    tar.entries()
+        .unwrap()
+        .map(|entry| {
+            let mut entry = entry.unwrap();
+            let mut contents = String::new();
+            entry.read_to_string(&mut contents).unwrap();
+            let path = entry.path().unwrap().to_string_lossy().into_owned();
+            (path, entry.header().mode().unwrap(), contents)
+        })
+        .collect()
+}
+
+fn assert_project(entries: &[(String, u32, String)]) {
+    assert!(entries.iter().any(|(path, ..)| path == "site/index.html"), "{:?}", entries);
+    for (path, mode, contents) in entries {
+        assert!(path.starts_with("site/"), "{}", path);
+        if path.ends_with('/') {
+            assert_eq!(*mode, 0o755, "{}", path);
+        } else {
+            assert_eq!(*mode, 0o644, "{}", path);
+        }
+        if path == "site/index.html" {
+            assert!(contents.contains("<html"), "{}", contents);
+        }
+    }
+    let manifest = entries.iter().find(|(path, ..)| path == "site/.webgen.json").unwrap();
+    assert!(manifest.2.contains("\"template\": \"static\""), "{}", manifest.2);
+}
+
+#[test]
+fn zip_archives_hold_one_project_folder() {
+    let out = output_dir("zip");
+
+    archive(&out, "site.zip");
+
+    assert_project(&read_zip(&out.join("site.zip")));
+    assert!(entries(&out, "site.zip").is_empty(), "{:?}", entries(&out, "site.zip"));
+}
+
+#[test]
+fn tar_gz_archives_hold_one_project_folder() {
+    let out = output_dir("tar-gz");
+
+    archive(&out, "site.tar.gz");
+
+    assert_project(&read_tar_gz(&out.join("site.tar.gz")));
+    assert!(entries(&out, "site.tar.gz").is_empty(), "{:?}", entries(&out, "site.tar.gz"));
+}
+
+#[test]
+fn existing_archives_are_only_overwritten_with_force() {
+    let out = output_dir("existing");
+    fs::write(out.join("site.zip"), "mine").unwrap();
+
+    let output = webgen(&out, &["--archive", "site.zip"]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Use --force to overwrite it"), "{}", stderr);
+    assert_eq!(fs::read_to_string(out.join("site.zip")).unwrap(), "mine");
+
+    let output = webgen(&out, &["--archive", "site.zip", "--force"]);
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_project(&read_zip(&out.join("site.zip")));
+}
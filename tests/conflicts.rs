@@ -0,0 +1,137 @@
+//! `webgen new` into a folder that already holds a customised copy of one of
+//! the generated files, with each way of resolving the conflict.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+const NAME: &str = "site";
+const CUSTOMISED: &str = "<p>mine</p>\n";
+
+/// An output folder for `case` holding the static template's project, with
+/// `index.html` changed.
+fn existing_project(case: &str) -> PathBuf {
+    let out = Path::new(env!("CARGO_TARGET_TMPDIR")).join("conflicts").join(case);
+    if out.exists() {
+        fs::remove_dir_all(&out).unwrap();
+    }
+    fs::create_dir_all(&out).unwrap();
+    let output = webgen(&out, &[]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    fs::write(out.join(NAME).join("index.html"), CUSTOMISED).unwrap();
+    out
+}
+
+fn webgen(out: &Path, args: &[&str]) -> Output {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_webgen"));
+    command
+        .current_dir(out)
+        .args(["new", NAME, "--template", "static", "--var", "year=2024", "--git=false", "--yes"])
+        .args(args)
+        .env("HOME", out)
+        .env("XDG_CONFIG_HOME", out)
+        .env("NO_COLOR", "1");
+    for (key, _) in env::vars_os() {
+        if key.to_string_lossy().starts_with("WEBGEN_") {
+            command.env_remove(key);
+        }
+    }
+    command.output().unwrap()
+}
+
+/// Runs webgen, expecting success, and returns what it printed.
+fn generate(out: &Path, args: &[&str]) -> String {
+    let output = webgen(out, args);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stdout).unwrap()
+}
+
+fn read(out: &Path, path: &str) -> String {
+    fs::read_to_string(out.join(NAME).join(path)).unwrap()
+}
+
+/// `index.html` as generated into a fresh folder.
+fn generated_index(case: &str) -> String {
+    let out = Path::new(env!("CARGO_TARGET_TMPDIR")).join("conflicts").join(format!("{}-fresh", case));
+    if out.exists() {
+        fs::remove_dir_all(&out).unwrap();
+    }
+    fs::create_dir_all(&out).unwrap();
+    generate(&out, &[]);
+    read(&out, "index.html")
+}
+
+#[test]
+fn existing_directories_are_refused_without_a_flag() {
+    let out = existing_project("refused");
+
+    let output = webgen(&out, &[]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Use --merge, --force or --skip-existing"), "{}", stderr);
+    assert_eq!(read(&out, "index.html"), CUSTOMISED);
+}
+
+#[test]
+fn force_overwrites_conflicting_files() {
+    let out = existing_project("force");
+
+    let stdout = generate(&out, &["--force"]);
+
+    assert_eq!(read(&out, "index.html"), generated_index("force"));
+    assert!(
+        stdout.contains("0 created, 1 overwritten, 0 kept, 0 written as .webgen-new, 5 unchanged"),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("overwritten index.html\n"), "{}", stdout);
+}
+
+#[test]
+fn skip_existing_keeps_conflicting_files() {
+    let out = existing_project("skip-existing");
+
+    let stdout = generate(&out, &["--skip-existing"]);
+
+    assert_eq!(read(&out, "index.html"), CUSTOMISED);
+    assert!(!out.join(NAME).join("index.html.webgen-new").exists());
+    assert!(
+        stdout.contains("0 created, 0 overwritten, 1 kept, 0 written as .webgen-new, 5 unchanged"),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("kept index.html\n"), "{}", stdout);
+}
+
+#[test]
+fn merge_without_prompts_writes_sidecars() {
+    let out = existing_project("merge");
+    fs::remove_file(out.join(NAME).join("README.md")).unwrap();
+
+    let stdout = generate(&out, &["--merge"]);
+
+    assert_eq!(read(&out, "index.html"), CUSTOMISED);
+    assert_eq!(read(&out, "index.html.webgen-new"), generated_index("merge"));
+    assert!(out.join(NAME).join("README.md").is_file());
+    assert!(
+        stdout.contains("1 created, 0 overwritten, 0 kept, 1 written as .webgen-new, 4 unchanged"),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("new index.html.webgen-new\n"), "{}", stdout);
+}
+
+#[test]
+fn merge_reports_conflicts_as_json() {
+    let out = existing_project("merge-json");
+
+    let stdout = generate(&out, &["--merge", "--format", "json"]);
+
+    let report: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(report["files"]["sidecars"], serde_json::json!(["index.html.webgen-new"]));
+    assert_eq!(report["files"]["created"], serde_json::json!([]));
+    let warnings = report["warnings"].as_array().unwrap();
+    assert!(warnings.iter().any(|w| w.as_str().unwrap().contains(".webgen-new copies")));
+}
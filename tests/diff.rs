@@ -0,0 +1,91 @@
+//! `webgen diff` on a project outside any git repository, with a modified,
+//! an added and a deleted file.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const NAME: &str = "site";
+
+/// A static project in a fresh folder outside any git work tree, so added
+/// files are found by walking it.
+fn project(case: &str) -> PathBuf {
+    let out = env::temp_dir().join(format!("webgen-diff-{}-{}", case, std::process::id()));
+    if out.exists() {
+        fs::remove_dir_all(&out).unwrap();
+    }
+    fs::create_dir_all(&out).unwrap();
+    webgen(&out, &["new", NAME, "--template", "static", "--var", "year=2024", "--git=false", "--yes"]);
+    out.join(NAME)
+}
+
+/// Runs webgen, expecting success, and returns what it printed.
+fn webgen(dir: &Path, args: &[&str]) -> String {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_webgen"));
+    command
+        .current_dir(dir)
+        .args(args)
+        .env("HOME", dir)
+        .env("XDG_CONFIG_HOME", dir)
+        .env("NO_COLOR", "1");
+    for (key, _) in env::vars_os() {
+        if key.to_string_lossy().starts_with("WEBGEN_") {
+            command.env_remove(key);
+        }
+    }
+    let output = command.output().unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stdout).unwrap()
+}
+
+/// Retitles `index.html`, adds `notes.txt` and an installed dependency, and
+/// deletes `README.md`.
+fn customise(dir: &Path) {
+    let index = fs::read_to_string(dir.join("index.html")).unwrap();
+    fs::write(dir.join("index.html"), index.replace("<title>site</title>", "<title>Mine</title>")).unwrap();
+    fs::write(dir.join("notes.txt"), "notes\n").unwrap();
+    fs::create_dir_all(dir.join("node_modules/left-pad")).unwrap();
+    fs::write(dir.join("node_modules/left-pad/index.js"), "module.exports = 1;\n").unwrap();
+    fs::remove_file(dir.join("README.md")).unwrap();
+}
+
+#[test]
+fn untouched_projects_have_no_differences() {
+    let dir = project("untouched");
+
+    let stdout = webgen(&dir, &["diff"]);
+
+    assert_eq!(stdout, "No differences from the Static HTML template.\n");
+}
+
+#[test]
+fn modified_files_are_shown_as_unified_diffs() {
+    let dir = project("patch");
+    customise(&dir);
+
+    let stdout = webgen(&dir, &["diff"]);
+
+    let hunk = "--- a/index.html\n+++ b/index.html\n@@ -3,7 +3,7 @@\n";
+    assert!(stdout.starts_with(hunk), "{}", stdout);
+    assert!(stdout.contains("\n-    <title>site</title>\n+    <title>Mine</title>\n"), "{}", stdout);
+    assert!(stdout.contains("\nAdded in the project:\n   notes.txt\n"), "{}", stdout);
+    assert!(stdout.ends_with("\nDeleted from the project:\n   README.md\n"), "{}", stdout);
+    assert!(!stdout.contains("node_modules"), "{}", stdout);
+}
+
+#[test]
+fn stat_summarises_each_file_and_the_totals() {
+    let dir = project("stat");
+    customise(&dir);
+
+    let stdout = webgen(&dir, &["diff", "--stat"]);
+
+    assert_eq!(
+        stdout,
+        " index.html |     2 +-\n \
+         notes.txt  | added\n \
+         README.md  | deleted\n \
+         1 file(s) changed, 1 insertion(s)(+), 1 deletion(s)(-), 1 added, 1 deleted\n"
+    );
+}
@@ -0,0 +1,177 @@
+//! Golden-file tests: every built-in template is generated with fixed inputs
+//! and the whole tree is compared against `tests/snapshots/<case>/`.
+//!
+//! After an intended change to a template, regenerate the snapshots with
+//!
+//!     WEBGEN_BLESS=1 cargo test --test snapshots
+//!
+//! and review the resulting diff before committing it.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+const NAME: &str = "my-app";
+
+/// Inputs shared by every case, so that nothing depends on the machine or the date.
+const FIXED: &[&str] = &[
+    "--author",
+    "Jane Doe",
+    "--description",
+    "A site generated for the snapshot tests",
+    "--var",
+    "year=2024",
+    "--git=false",
+    "--yes",
+];
+
+macro_rules! snapshot {
+    ($case:ident, $template:literal $(, $arg:literal)* $(,)?) => {
+        #[test]
+        fn $case() {
+            check(stringify!($case), $template, &[$($arg),*]);
+        }
+    };
+}
+
+snapshot!(flask, "flask");
+snapshot!(flask_full, "flask", "--docker", "--ci", "github", "--tests", "--profile", "PROFILE");
+snapshot!(mern, "mern");
+snapshot!(mern_full, "mern", "--typescript", "--css", "tailwind", "--docker", "--ci", "github", "--tests");
+snapshot!(mern_gitlab_pnpm, "mern", "--package-manager", "pnpm", "--ci", "gitlab", "--docker");
+snapshot!(nextjs, "nextjs");
+snapshot!(nextjs_full, "nextjs", "--typescript", "--css", "tailwind", "--docker", "--ci", "github", "--tests");
+snapshot!(react, "react");
+snapshot!(react_full, "react", "--typescript", "--css", "tailwind", "--docker", "--ci", "github", "--tests", "--profile", "PROFILE");
+snapshot!(react_scss_yarn, "react", "--css", "scss", "--package-manager", "yarn", "--ci", "gitlab");
+snapshot!(static_html, "static");
+snapshot!(static_full, "static", "--css", "tailwind", "--docker", "--profile", "PROFILE");
+snapshot!(vue, "vue");
+snapshot!(vue_full, "vue", "--typescript", "--css", "tailwind", "--docker", "--ci", "github", "--tests", "--profile", "PROFILE");
+
+fn check(case: &str, template: &str, args: &[&str]) {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests");
+    let profile = root.join("fixtures").join("profile.yaml");
+    let out = Path::new(env!("CARGO_TARGET_TMPDIR")).join("snapshots").join(case);
+    if out.exists() {
+        fs::remove_dir_all(&out).unwrap();
+    }
+    fs::create_dir_all(&out).unwrap();
+
+    let mut command = Command::new(env!("CARGO_BIN_EXE_webgen"));
+    command
+        .current_dir(&out)
+        .args(["new", NAME, "--template", template])
+        .args(FIXED)
+        .args(args.iter().map(|&arg| if arg == "PROFILE" { profile.as_os_str() } else { arg.as_ref() }))
+        // Keep the developer's settings, user templates and the package manager
+        // `cargo test` may have been launched through out of the output.
+        .env("HOME", &out)
+        .env("XDG_CONFIG_HOME", &out)
+        .env_remove("npm_config_user_agent");
+    for (key, _) in env::vars_os() {
+        if key.to_string_lossy().starts_with("WEBGEN_") {
+            command.env_remove(key);
+        }
+    }
+    let output = command.output().unwrap();
+    assert!(
+        output.status.success(),
+        "webgen failed for {}:\n{}",
+        case,
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let actual = read_tree(&out.join(NAME));
+    let expected_dir = root.join("snapshots").join(case);
+    if env::var("WEBGEN_BLESS").is_ok_and(|v| !v.is_empty() && v != "0") {
+        bless(&expected_dir, &actual);
+        return;
+    }
+
+    let expected = read_tree(&expected_dir);
+    let mut problems = Vec::new();
+    for (path, contents) in &actual {
+        match expected.get(path) {
+            None => problems.push(format!("not in the snapshot: {}", path)),
+            Some(snapshot) if snapshot != contents => problems.push(changed(path, snapshot, contents)),
+            Some(_) => {}
+        }
+    }
+    for path in expected.keys().filter(|path| !actual.contains_key(*path)) {
+        problems.push(format!("no longer generated: {}", path));
+    }
+    assert!(
+        problems.is_empty(),
+        "{} differs from tests/snapshots/{}:\n\n{}\n\n\
+         If the change is intended, run `WEBGEN_BLESS=1 cargo test --test snapshots` and review the diff.",
+        case,
+        case,
+        problems.join("\n")
+    );
+}
+
+/// Files under `dir` keyed by their `/`-separated relative path.
+fn read_tree(dir: &Path) -> BTreeMap<String, String> {
+    let mut files = BTreeMap::new();
+    let mut pending = vec![dir.to_path_buf()];
+    while let Some(current) = pending.pop() {
+        let Ok(entries) = fs::read_dir(&current) else {
+            continue;
+        };
+        for entry in entries {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                pending.push(path);
+            } else {
+                files.insert(relative(dir, &path), fs::read_to_string(&path).unwrap());
+            }
+        }
+    }
+    files
+}
+
+fn relative(dir: &Path, path: &Path) -> String {
+    let parts: Vec<_> = path
+        .strip_prefix(dir)
+        .unwrap()
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy().into_owned())
+        .collect();
+    parts.join("/")
+}
+
+/// The first line that differs, which is usually enough to spot the change.
+fn changed(path: &str, expected: &str, actual: &str) -> String {
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    let mut line = 1;
+    loop {
+        match (expected_lines.next(), actual_lines.next()) {
+            (Some(e), Some(a)) if e == a => line += 1,
+            (None, None) => return format!("changed {} (line endings or trailing newline)", path),
+            (e, a) => {
+                return format!(
+                    "changed {} at line {}:\n  - {}\n  + {}",
+                    path,
+                    line,
+                    e.unwrap_or("<end of file>"),
+                    a.unwrap_or("<end of file>")
+                );
+            }
+        }
+    }
+}
+
+fn bless(dir: &Path, files: &BTreeMap<String, String>) {
+    if dir.exists() {
+        fs::remove_dir_all(dir).unwrap();
+    }
+    for (path, contents) in files {
+        let dest = dir.join(path);
+        fs::create_dir_all(dest.parent().unwrap()).unwrap();
+        fs::write(dest, contents).unwrap();
+    }
+}
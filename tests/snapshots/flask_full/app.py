@@ -0,0 +1,51 @@
+from flask import Flask, render_template, request, jsonify
+import os
+
+app = Flask(__name__)
+
+@app.route('/')
+def index():
+    return render_template('index.html')
+
+@app.route('/about')
+def about():
+    experience = [
+        {
+            'company': 'Analytical Society',
+            'position': 'Programmer',
+            'period': 'Oct 1842 – Present',
+            'summary': 'Translated and annotated Menabrea\'s paper.',
+            'highlights': ['Wrote Note G']
+        },
+        {
+            'company': 'Self-employed',
+            'position': 'Mathematician',
+            'period': '1835 – Sep 1842',
+            'summary': '',
+            'highlights': []
+        },
+    ]
+    return render_template('about.html', experience=experience)
+
+@app.route('/projects')
+def projects():
+    projects_data = [
+        {
+            'title': 'Note G',
+            'description': 'The first published algorithm',
+            'tech': ['Analytical Engine'],
+            'github': 'https://example.com/note-g'
+        },
+    ]
+    return render_template('projects.html', projects=projects_data)
+
+@app.route('/contact', methods=['GET', 'POST'])
+def contact():
+    if request.method == 'POST':
+        data = request.form
+        # Handle form submission here
+        return jsonify({'status': 'success', 'message': 'Message received!'})
+    return render_template('contact.html')
+
+if __name__ == '__main__':
+    app.run(debug=True, host='0.0.0.0', port=5000)
@@ -0,0 +1,42 @@
+import pytest
+from markupsafe import escape
+
+from app import app
+
+PROJECTS = [
+    'Note G',
+]
+
+
+@pytest.fixture
+def client():
+    app.config.update(TESTING=True)
+    with app.test_client() as client:
+        yield client
+
+
+@pytest.mark.parametrize('path', ['/', '/about', '/projects', '/contact'])
+def test_page_renders(client, path):
+    response = client.get(path)
+    assert response.status_code == 200
+    assert response.mimetype == 'text/html'
+
+
+def test_projects_lists_every_project(client):
+    page = client.get('/projects').get_data(as_text=True)
+    for name in PROJECTS:
+        assert str(escape(name)) in page
+
+
+def test_contact_accepts_a_message(client):
+    response = client.post('/contact', data={
+        'name': 'Ada Lovelace',
+        'email': 'ada@example.com',
+        'message': 'Hello!',
+    })
+    assert response.status_code == 200
+    assert response.get_json()['status'] == 'success'
+
+
+def test_unknown_page_is_not_found(client):
+    assert client.get('/does-not-exist').status_code == 404
@@ -0,0 +1,39 @@
+const express = require('express');
+const router = express.Router();
+const User = require('../models/User');
+
+// Get all users
+router.get('/users', async (req, res) => {
+  try {
+    const users = await User.find().select('-password');
+    res.json(users);
+  } catch (error) {
+    res.status(500).json({ message: error.message });
+  }
+});
+
+// Create user
+router.post('/users', async (req, res) => {
+  const user = new User({
+    name: req.body.name,
+    email: req.body.email,
+    password: req.body.password // Remember to hash in production!
+  });
+
+  try {
+    const newUser = await user.save();
+    res.status(201).json(newUser);
+  } catch (error) {
+    res.status(400).json({ message: error.message });
+  }
+});
+
+// Sample data endpoint
+router.get('/data', (req, res) => {
+  res.json({
+    message: 'API is working!',
+    data: ['Item 1', 'Item 2', 'Item 3']
+  });
+});
+
+module.exports = router;
@@ -0,0 +1,58 @@
+import React, { useState, useEffect } from 'react';
+import axios from 'axios';
+import './App.css';
+
+function App() {
+  const [data, setData] = useState(null);
+  const [loading, setLoading] = useState(true);
+
+  useEffect(() => {
+    const fetchData = async () => {
+      try {
+        const response = await axios.get('/api/data');
+        setData(response.data);
+        setLoading(false);
+      } catch (error) {
+        console.error('Error fetching data:', error);
+        setLoading(false);
+      }
+    };
+
+    fetchData();
+  }, []);
+
+  return (
+    <div className="App">
+      <header className="App-header">
+        <h1>{ "my-app" }</h1>
+        <div className="content">
+          {loading ? (
+            <p>Loading...</p>
+          ) : data ? (
+            <div>
+              <p>{data.message}</p>
+              <ul>
+                {data.data && data.data.map((item, index) => (
+                  <li key={index}>{item}</li>
+                ))}
+              </ul>
+            </div>
+          ) : (
+            <p>No data available. Make sure the backend is running!</p>
+          )}
+        </div>
+        <div className="info">
+          <h3>Stack:</h3>
+          <ul className="stack-list">
+            <li>⚡ MongoDB - Database</li>
+            <li>🚀 Express - Backend Framework</li>
+            <li>⚛️ React - Frontend Library</li>
+            <li>💚 Node.js - Runtime</li>
+          </ul>
+        </div>
+      </header>
+    </div>
+  );
+}
+
+export default App;
@@ -0,0 +1,40 @@
+import { Router, Request, Response } from 'express';
+import User from '../models/User';
+
+const router = Router();
+
+// Get all users
+router.get('/users', async (req: Request, res: Response) => {
+  try {
+    const users = await User.find().select('-password');
+    res.json(users);
+  } catch (error) {
+    res.status(500).json({ message: (error as Error).message });
+  }
+});
+
+// Create user
+router.post('/users', async (req: Request, res: Response) => {
+  const user = new User({
+    name: req.body.name,
+    email: req.body.email,
+    password: req.body.password // Remember to hash in production!
+  });
+
+  try {
+    const newUser = await user.save();
+    res.status(201).json(newUser);
+  } catch (error) {
+    res.status(400).json({ message: (error as Error).message });
+  }
+});
+
+// Sample data endpoint
+router.get('/data', (req: Request, res: Response) => {
+  res.json({
+    message: 'API is working!',
+    data: ['Item 1', 'Item 2', 'Item 3']
+  });
+});
+
+export default router;
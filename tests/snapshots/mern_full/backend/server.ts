@@ -0,0 +1,29 @@
+import express, { Request, Response } from 'express';
+import mongoose from 'mongoose';
+import cors from 'cors';
+import 'dotenv/config';
+import apiRoutes from './routes/api';
+
+const app = express();
+const PORT = process.env.PORT || 5000;
+const PROJECT_NAME = 'my-app';
+
+// Middleware
+app.use(cors());
+app.use(express.json());
+
+// MongoDB Connection
+mongoose.connect(process.env.MONGODB_URI || 'mongodb://localhost:27017/my-app')
+.then(() => console.log('MongoDB connected'))
+.catch(err => console.log('MongoDB connection error:', err));
+
+// Routes
+app.use('/api', apiRoutes);
+
+app.get('/', (req: Request, res: Response) => {
+  res.json({ message: `Welcome to ${PROJECT_NAME} API` });
+});
+
+app.listen(PORT, () => {
+  console.log(`Server running on port ${PORT}`);
+});
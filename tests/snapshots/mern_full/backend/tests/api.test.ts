@@ -0,0 +1,68 @@
+import { afterEach, describe, it, mock } from 'node:test';
+import assert from 'node:assert/strict';
+import express from 'express';
+import request from 'supertest';
+import apiRoutes from '../routes/api';
+import User from '../models/User';
+
+const app = express();
+app.use(express.json());
+app.use('/api', apiRoutes);
+
+afterEach(() => mock.restoreAll());
+
+describe('GET /api/data', () => {
+  it('returns the sample data', async () => {
+    const res = await request(app).get('/api/data').expect(200);
+
+    assert.equal(res.body.message, 'API is working!');
+    assert.deepEqual(res.body.data, ['Item 1', 'Item 2', 'Item 3']);
+  });
+});
+
+describe('GET /api/users', () => {
+  it('lists users without their passwords', async () => {
+    const users = [{ name: 'Ada Lovelace', email: 'ada@example.com' }];
+    const select = mock.fn(async () => users);
+    mock.method(User, 'find', () => ({ select }));
+
+    const res = await request(app).get('/api/users').expect(200);
+
+    assert.deepEqual(res.body, users);
+    assert.deepEqual(select.mock.calls[0].arguments, ['-password']);
+  });
+
+  it('reports database errors', async () => {
+    mock.method(User, 'find', () => ({
+      select: async () => {
+        throw new Error('connection lost');
+      },
+    }));
+
+    const res = await request(app).get('/api/users').expect(500);
+
+    assert.equal(res.body.message, 'connection lost');
+  });
+});
+
+describe('POST /api/users', () => {
+  it('creates a user', async () => {
+    mock.method(User.prototype, 'save', async function (this: unknown) {
+      return this;
+    });
+
+    const res = await request(app)
+      .post('/api/users')
+      .send({ name: 'Ada Lovelace', email: 'ada@example.com', password: 'secret' })
+      .expect(201);
+
+    assert.equal(res.body.name, 'Ada Lovelace');
+    assert.equal(res.body.email, 'ada@example.com');
+  });
+
+  it('rejects invalid users', async () => {
+    const res = await request(app).post('/api/users').send({ name: 'No Email' }).expect(400);
+
+    assert.match(res.body.message, /email/);
+  });
+});
@@ -0,0 +1,63 @@
+import React, { useState, useEffect } from 'react';
+import axios from 'axios';
+import './App.css';
+
+interface ApiData {
+  message: string;
+  data?: string[];
+}
+
+function App() {
+  const [data, setData] = useState<ApiData | null>(null);
+  const [loading, setLoading] = useState(true);
+
+  useEffect(() => {
+    const fetchData = async () => {
+      try {
+        const response = await axios.get<ApiData>('/api/data');
+        setData(response.data);
+        setLoading(false);
+      } catch (error) {
+        console.error('Error fetching data:', error);
+        setLoading(false);
+      }
+    };
+
+    fetchData();
+  }, []);
+
+  return (
+    <div className="min-h-screen bg-gradient-to-br from-[#667eea] to-[#764ba2] text-center">
+      <header className="flex min-h-screen flex-col items-center justify-center p-5 text-xl text-white md:text-2xl">
+        <h1 className="mb-8 text-4xl font-bold md:text-5xl">{ "my-app" }</h1>
+        <div className="my-8 w-11/12 rounded-xl bg-white/10 p-8 md:w-auto md:min-w-[400px]">
+          {loading ? (
+            <p>Loading...</p>
+          ) : data ? (
+            <div>
+              <p>{data.message}</p>
+              <ul className="mt-4 space-y-2">
+                {data.data && data.data.map((item, index) => (
+                  <li key={index} className="rounded-md bg-white/20 p-2">{item}</li>
+                ))}
+              </ul>
+            </div>
+          ) : (
+            <p>No data available. Make sure the backend is running!</p>
+          )}
+        </div>
+        <div className="mt-8 rounded-xl bg-white/10 p-6">
+          <h3 className="mb-2 font-semibold">Stack:</h3>
+          <ul className="space-y-2 text-left text-lg">
+            <li>⚡ MongoDB - Database</li>
+            <li>🚀 Express - Backend Framework</li>
+            <li>⚛️ React - Frontend Library</li>
+            <li>💚 Node.js - Runtime</li>
+          </ul>
+        </div>
+      </header>
+    </div>
+  );
+}
+
+export default App;
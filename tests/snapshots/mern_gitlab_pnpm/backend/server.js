@@ -0,0 +1,31 @@
+const express = require('express');
+const mongoose = require('mongoose');
+const cors = require('cors');
+require('dotenv').config();
+
+const app = express();
+const PORT = process.env.PORT || 5000;
+const PROJECT_NAME = 'my-app';
+
+// Middleware
+app.use(cors());
+app.use(express.json());
+
+// MongoDB Connection
+mongoose.connect(process.env.MONGODB_URI || 'mongodb://localhost:27017/my-app', {
+  useNewUrlParser: true,
+  useUnifiedTopology: true,
+})
+.then(() => console.log('MongoDB connected'))
+.catch(err => console.log('MongoDB connection error:', err));
+
+// Routes
+app.use('/api', require('./routes/api'));
+
+app.get('/', (req, res) => {
+  res.json({ message: `Welcome to ${PROJECT_NAME} API` });
+});
+
+app.listen(PORT, () => {
+  console.log(`Server running on port ${PORT}`);
+});
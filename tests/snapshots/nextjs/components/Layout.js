@@ -0,0 +1,19 @@
+import Link from 'next/link'
+export default function Layout({ children }) {
+  return (
+    <>
+      <nav className="navbar">
+        <div className="container">
+          <div className="nav-content">
+            <div className="logo">{ "my-app" }</div>
+            <ul className="nav-links">
+              <li><Link href="/">Home</Link></li>
+              <li><Link href="/about">About</Link></li>
+            </ul>
+          </div>
+        </div>
+      </nav>
+      <main>{children}</main>
+    </>
+  )
+}
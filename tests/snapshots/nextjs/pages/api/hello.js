@@ -0,0 +1,6 @@
+export default function handler(req, res) {
+  res.status(200).json({ 
+    message: 'Hello from Next.js API!',
+    timestamp: new Date().toISOString()
+  })
+}
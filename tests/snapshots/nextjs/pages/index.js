@@ -0,0 +1,38 @@
+import Link from 'next/link'
+import Layout from '../components/Layout'
+
+export default function Home() {
+  return (
+    <Layout>
+      <div className="hero">
+        <h1>Welcome to { "my-app" }</h1>
+        <p>{ "A site generated for the snapshot tests" }</p>
+        <div className="buttons">
+          <Link href="/about" className="btn btn-primary">About</Link>
+          {/* An API route rather than a page, so a full page load is intended. */}
+          {/* eslint-disable-next-line @next/next/no-html-link-for-pages */}
+          <a href="/api/hello" className="btn btn-secondary">API Demo</a>
+        </div>
+      </div>
+      
+      <div className="features">
+        <div className="feature-card">
+          <h3>⚡ Fast</h3>
+          <p>Server-side rendering and static generation</p>
+        </div>
+        <div className="feature-card">
+          <h3>🔄 File-based Routing</h3>
+          <p>Automatic routing based on file structure</p>
+        </div>
+        <div className="feature-card">
+          <h3>🎨 CSS Support</h3>
+          <p>Built-in CSS and Sass support</p>
+        </div>
+        <div className="feature-card">
+          <h3>📦 API Routes</h3>
+          <p>Build APIs with serverless functions</p>
+        </div>
+      </div>
+    </Layout>
+  )
+}
@@ -0,0 +1,16 @@
+import type { NextApiRequest, NextApiResponse } from 'next'
+import handler from '../../pages/api/hello'
+
+describe('/api/hello', () => {
+  it('responds with a greeting', () => {
+    const json = jest.fn()
+    const status = jest.fn(() => ({ json }))
+
+    handler({} as NextApiRequest, { status } as unknown as NextApiResponse)
+
+    expect(status).toHaveBeenCalledWith(200)
+    expect(json).toHaveBeenCalledWith(
+      expect.objectContaining({ message: 'Hello from Next.js API!' })
+    )
+  })
+})
@@ -0,0 +1,18 @@
+import { render, screen } from '@testing-library/react'
+import Home from '../pages/index'
+
+describe('Home', () => {
+  it('renders the welcome heading', () => {
+    render(<Home />)
+
+    expect(screen.getByRole('heading', { level: 1 })).toHaveTextContent("my-app")
+  })
+
+  it('links to the about page', () => {
+    render(<Home />)
+
+    for (const link of screen.getAllByRole('link', { name: 'About' })) {
+      expect(link).toHaveAttribute('href', '/about')
+    }
+  })
+})
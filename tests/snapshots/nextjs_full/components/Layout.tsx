@@ -0,0 +1,21 @@
+import Link from 'next/link'
+import type { ReactNode } from 'react'
+
+export default function Layout({ children }: { children: ReactNode }) {
+  return (
+    <>
+      <nav className="bg-white py-4 shadow-md">
+        <div className="mx-auto max-w-6xl px-5">
+          <div className="flex items-center justify-between">
+            <div className="text-2xl font-bold text-primary">{ "my-app" }</div>
+            <ul className="flex gap-4 md:gap-8">
+              <li><Link href="/" className="transition-colors hover:text-primary">Home</Link></li>
+              <li><Link href="/about" className="transition-colors hover:text-primary">About</Link></li>
+            </ul>
+          </div>
+        </div>
+      </nav>
+      <main>{children}</main>
+    </>
+  )
+}
@@ -0,0 +1,9 @@
+const nextJest = require('next/jest')
+
+// Loads next.config.js and .env files and compiles tests with Next's SWC setup.
+const createJestConfig = nextJest({ dir: './' })
+
+module.exports = createJestConfig({
+  testEnvironment: 'jsdom',
+  setupFilesAfterEnv: ['<rootDir>/jest.setup.ts'],
+})
@@ -0,0 +1,8 @@
+/** @type {import('next').NextConfig} */
+const nextConfig = {
+  reactStrictMode: true,
+  // Bundles a minimal server into .next/standalone for the Docker image.
+  output: 'standalone',
+}
+
+module.exports = nextConfig
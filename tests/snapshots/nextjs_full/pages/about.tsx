@@ -0,0 +1,22 @@
+import Link from 'next/link'
+import Layout from '../components/Layout'
+
+export default function About() {
+  return (
+    <Layout>
+      <div className="mx-auto max-w-3xl px-5 py-20">
+        <h1 className="mb-8 text-4xl font-bold">About This App</h1>
+        <p>This is a Next.js template application.</p>
+        <p>It includes:</p>
+        <ul className="my-8 list-disc space-y-2 pl-8">
+          <li>Server-side rendering (SSR)</li>
+          <li>Static site generation (SSG)</li>
+          <li>API routes</li>
+          <li>File-based routing</li>
+          <li>Optimized performance</li>
+        </ul>
+        <Link href="/" className="btn btn-primary">Back to Home</Link>
+      </div>
+    </Layout>
+  )
+}
@@ -0,0 +1,38 @@
+import Link from 'next/link'
+import Layout from '../components/Layout'
+
+export default function Home() {
+  return (
+    <Layout>
+      <div className="bg-gradient-to-br from-primary to-secondary px-5 py-24 text-center text-white">
+        <h1 className="mb-4 text-4xl font-bold md:text-5xl">Welcome to { "my-app" }</h1>
+        <p className="mb-8 text-xl opacity-90 md:text-2xl">{ "A site generated for the snapshot tests" }</p>
+        <div className="flex justify-center gap-4">
+          <Link href="/about" className="btn bg-white text-primary hover:-translate-y-0.5 hover:shadow-lg">About</Link>
+          {/* An API route rather than a page, so a full page load is intended. */}
+          {/* eslint-disable-next-line @next/next/no-html-link-for-pages */}
+          <a href="/api/hello" className="btn btn-secondary">API Demo</a>
+        </div>
+      </div>
+      
+      <div className="mx-auto grid max-w-6xl gap-8 px-5 py-20 sm:grid-cols-2 lg:grid-cols-4">
+        <div className="rounded-xl bg-white p-8 shadow-lg transition-transform hover:-translate-y-1">
+          <h3 className="mb-4 text-lg font-semibold text-primary">⚡ Fast</h3>
+          <p>Server-side rendering and static generation</p>
+        </div>
+        <div className="rounded-xl bg-white p-8 shadow-lg transition-transform hover:-translate-y-1">
+          <h3 className="mb-4 text-lg font-semibold text-primary">🔄 File-based Routing</h3>
+          <p>Automatic routing based on file structure</p>
+        </div>
+        <div className="rounded-xl bg-white p-8 shadow-lg transition-transform hover:-translate-y-1">
+          <h3 className="mb-4 text-lg font-semibold text-primary">🎨 CSS Support</h3>
+          <p>Built-in CSS and Sass support</p>
+        </div>
+        <div className="rounded-xl bg-white p-8 shadow-lg transition-transform hover:-translate-y-1">
+          <h3 className="mb-4 text-lg font-semibold text-primary">📦 API Routes</h3>
+          <p>Build APIs with serverless functions</p>
+        </div>
+      </div>
+    </Layout>
+  )
+}
@@ -0,0 +1,13 @@
+import React from 'react';
+
+function Footer() {
+  return (
+    <footer className="footer">
+      <div className="container">
+        <p>&copy; 2024 { "Jane Doe" }. Built with React.</p>
+      </div>
+    </footer>
+  );
+}
+
+export default Footer;
@@ -0,0 +1,21 @@
+import React from 'react';
+
+function Header() {
+  return (
+    <header className="header">
+      <div className="container">
+        <div className="logo">{ "my-app" }</div>
+        <nav>
+          <ul className="nav">
+            <li><a href="#hero">Home</a></li>
+            <li><a href="#about">About</a></li>
+            <li><a href="#projects">Projects</a></li>
+            <li><a href="#contact">Contact</a></li>
+          </ul>
+        </nav>
+      </div>
+    </header>
+  );
+}
+
+export default Header;
@@ -0,0 +1,13 @@
+import React from 'react';
+import { render, screen } from '@testing-library/react';
+import App from './App';
+
+test('renders every section', () => {
+  render(<App />);
+
+  expect(screen.getByRole('heading', { level: 1 })).toHaveTextContent("Hi, I'm");
+  expect(screen.getByRole('heading', { name: 'About Me' })).toBeInTheDocument();
+  expect(screen.getByRole('heading', { name: 'Experience' })).toBeInTheDocument();
+  expect(screen.getByRole('heading', { name: 'My Projects' })).toBeInTheDocument();
+  expect(screen.getByRole('heading', { name: 'Get In Touch' })).toBeInTheDocument();
+});
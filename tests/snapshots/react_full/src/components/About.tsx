@@ -0,0 +1,32 @@
+import React from 'react';
+
+interface Skill {
+  title: string;
+  description: string;
+}
+
+function About() {
+  const skills: Skill[] = [
+    { title: 'Mathematics', description: 'Calculus, Bernoulli numbers' },
+    { title: 'Translation', description: 'French to English, with notes longer than the original.' }
+  ];
+
+  return (
+    <section className="py-20" id="about">
+      <div className="mx-auto max-w-6xl px-5">
+        <h2 className="mb-12 text-center text-4xl font-bold">About Me</h2>
+        <p className="mx-auto mb-12 max-w-3xl text-center text-lg">{ "I write programs for machines that don't exist yet." }</p>
+        <div className="grid gap-8 sm:grid-cols-2 lg:grid-cols-4">
+          {skills.map((skill, index) => (
+            <div key={index} className="rounded-xl bg-white p-8 shadow-lg transition-transform hover:-translate-y-1">
+              <h3 className="mb-4 text-lg font-semibold text-primary">{skill.title}</h3>
+              <p>{skill.description}</p>
+            </div>
+          ))}
+        </div>
+      </div>
+    </section>
+  );
+}
+
+export default About;
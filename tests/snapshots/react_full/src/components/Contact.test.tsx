@@ -0,0 +1,18 @@
+import React from 'react';
+import { fireEvent, render, screen } from '@testing-library/react';
+import Contact from './Contact';
+
+test('clears the form once a message is sent', () => {
+  const alert = jest.spyOn(window, 'alert').mockImplementation(() => {});
+  render(<Contact />);
+
+  fireEvent.change(screen.getByLabelText('Name'), { target: { value: 'Ada Lovelace' } });
+  fireEvent.change(screen.getByLabelText('Email'), { target: { value: 'ada@example.com' } });
+  fireEvent.change(screen.getByLabelText('Message'), { target: { value: 'Hello!' } });
+  fireEvent.click(screen.getByRole('button', { name: 'Send Message' }));
+
+  expect(alert).toHaveBeenCalledTimes(1);
+  expect(screen.getByLabelText('Name')).toHaveValue('');
+  expect(screen.getByLabelText('Message')).toHaveValue('');
+  alert.mockRestore();
+});
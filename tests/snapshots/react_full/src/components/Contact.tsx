@@ -0,0 +1,77 @@
+import React, { useState } from 'react';
+
+function Contact() {
+  const [formData, setFormData] = useState({
+    name: '',
+    email: '',
+    message: ''
+  });
+
+  const handleSubmit = (e: React.FormEvent<HTMLFormElement>) => {
+    e.preventDefault();
+    alert('Message sent! (This is a demo)');
+    setFormData({ name: '', email: '', message: '' });
+  };
+
+  const handleChange = (e: React.ChangeEvent<HTMLInputElement | HTMLTextAreaElement>) => {
+    setFormData({
+      ...formData,
+      [e.target.name]: e.target.value
+    });
+  };
+
+  return (
+    <section className="py-20" id="contact">
+      <div className="mx-auto max-w-6xl px-5">
+        <h2 className="mb-12 text-center text-4xl font-bold">Get In Touch</h2>
+        <form className="mx-auto max-w-xl" onSubmit={handleSubmit}>
+          <div className="mb-6">
+            <label htmlFor="name" className="mb-2 block font-medium">Name</label>
+            <input
+              type="text"
+              id="name"
+              className="w-full rounded-md border border-slate-300 p-3 focus:border-primary focus:outline-none"
+              name="name"
+              value={formData.name}
+              onChange={handleChange}
+              required
+            />
+          </div>
+          <div className="mb-6">
+            <label htmlFor="email" className="mb-2 block font-medium">Email</label>
+            <input
+              type="email"
+              id="email"
+              className="w-full rounded-md border border-slate-300 p-3 focus:border-primary focus:outline-none"
+              name="email"
+              value={formData.email}
+              onChange={handleChange}
+              required
+            />
+          </div>
+          <div className="mb-6">
+            <label htmlFor="message" className="mb-2 block font-medium">Message</label>
+            <textarea
+              id="message"
+              className="w-full rounded-md border border-slate-300 p-3 focus:border-primary focus:outline-none"
+              name="message"
+              rows={5}
+              value={formData.message}
+              onChange={handleChange}
+              required
+            ></textarea>
+          </div>
+          <button type="submit" className="btn btn-primary">Send Message</button>
+        </form>
+        <p className="mt-8 text-center">
+          Or email me at <a href="mailto:ada@example.com" className="font-medium text-primary">{ "ada@example.com" }</a>
+        </p>
+        <div className="mt-8 flex justify-center gap-8 font-medium text-primary">
+          <a href="https://github.com/ada" target="_blank" rel="noopener noreferrer">{ "GitHub" }</a>
+        </div>
+      </div>
+    </section>
+  );
+}
+
+export default Contact;
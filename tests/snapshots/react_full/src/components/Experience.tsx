@@ -0,0 +1,57 @@
+import React from 'react';
+
+interface Job {
+  company: string;
+  position: string;
+  period: string;
+  summary: string;
+  highlights: string[];
+}
+
+function Experience() {
+  const jobs: Job[] = [
+    {
+      company: 'Analytical Society',
+      position: 'Programmer',
+      period: 'Oct 1842 – Present',
+      summary: 'Translated and annotated Menabrea\'s paper.',
+      highlights: ['Wrote Note G']
+    },
+    {
+      company: 'Self-employed',
+      position: 'Mathematician',
+      period: '1835 – Sep 1842',
+      summary: '',
+      highlights: []
+    }
+  ];
+
+  return (
+    <section className="py-20" id="experience">
+      <div className="mx-auto max-w-6xl px-5">
+        <h2 className="mb-12 text-center text-4xl font-bold">Experience</h2>
+        <ol className="mx-auto max-w-3xl border-l-4 border-primary pl-8">
+          {jobs.map((job, index) => (
+            <li key={index} className="relative mb-10">
+              <span className="absolute -left-[2.55rem] top-1.5 h-4 w-4 rounded-full bg-primary" />
+              <h3 className="text-lg font-semibold text-primary">
+                {job.position}{job.position && job.company ? ' · ' : ''}{job.company}
+              </h3>
+              {job.period && <p className="mb-2 text-sm text-slate-500">{job.period}</p>}
+              {job.summary && <p>{job.summary}</p>}
+              {job.highlights.length > 0 && (
+                <ul className="mt-2 list-disc pl-5">
+                  {job.highlights.map((highlight, i) => (
+                    <li key={i}>{highlight}</li>
+                  ))}
+                </ul>
+              )}
+            </li>
+          ))}
+        </ol>
+      </div>
+    </section>
+  );
+}
+
+export default Experience;
@@ -0,0 +1,13 @@
+import React from 'react';
+
+function Footer() {
+  return (
+    <footer className="bg-slate-800 py-8 text-center text-white">
+      <div className="mx-auto max-w-6xl px-5">
+        <p>&copy; 2024 { "Jane Doe" }. Built with React.</p>
+      </div>
+    </footer>
+  );
+}
+
+export default Footer;
@@ -0,0 +1,22 @@
+import React from 'react';
+
+function Header() {
+  return (
+    <header className="sticky top-0 z-50 bg-white shadow-md">
+      <div className="mx-auto flex max-w-6xl items-center justify-between px-5 py-4">
+        <div className="text-2xl font-bold text-primary">{ "my-app" }</div>
+        <nav>
+          <ul className="flex gap-4 md:gap-8">
+            <li><a href="#hero" className="transition-colors hover:text-primary">Home</a></li>
+            <li><a href="#about" className="transition-colors hover:text-primary">About</a></li>
+            <li><a href="#experience" className="transition-colors hover:text-primary">Experience</a></li>
+            <li><a href="#projects" className="transition-colors hover:text-primary">Projects</a></li>
+            <li><a href="#contact" className="transition-colors hover:text-primary">Contact</a></li>
+          </ul>
+        </nav>
+      </div>
+    </header>
+  );
+}
+
+export default Header;
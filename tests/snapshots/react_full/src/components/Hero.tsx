@@ -0,0 +1,18 @@
+import React from 'react';
+
+function Hero() {
+  return (
+    <section className="bg-gradient-to-br from-primary to-secondary py-24 text-center text-white" id="hero">
+      <div className="mx-auto max-w-6xl px-5">
+        <h1 className="mb-4 text-4xl font-bold md:text-5xl">Hi, I'm { "Jane Doe" }</h1>
+        <p className="mb-8 text-xl opacity-90 md:text-2xl">{ "Analytical Engine Programmer" }</p>
+        <div className="flex justify-center gap-4">
+          <a href="#projects" className="btn bg-white text-primary hover:-translate-y-0.5 hover:shadow-lg">View Projects</a>
+          <a href="#contact" className="btn btn-secondary">Get in Touch</a>
+        </div>
+      </div>
+    </section>
+  );
+}
+
+export default Hero;
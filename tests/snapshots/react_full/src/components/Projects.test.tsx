@@ -0,0 +1,16 @@
+import React from 'react';
+import { render, screen } from '@testing-library/react';
+import Projects from './Projects';
+
+const titles: string[] = [
+  'Note G',
+];
+
+test('shows a card for every project', () => {
+  render(<Projects />);
+
+  for (const title of titles) {
+    expect(screen.getByRole('heading', { name: title })).toBeInTheDocument();
+  }
+  expect(screen.queryAllByRole('link', { name: 'View on GitHub' })).toHaveLength(titles.length);
+});
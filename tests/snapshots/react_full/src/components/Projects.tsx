@@ -0,0 +1,45 @@
+import React from 'react';
+
+interface Project {
+  title: string;
+  description: string;
+  tech: string[];
+  github: string;
+}
+
+function Projects() {
+  const projects: Project[] = [
+    {
+      title: 'Note G',
+      description: 'The first published algorithm',
+      tech: ['Analytical Engine'],
+      github: 'https://example.com/note-g'
+    }
+  ];
+
+  return (
+    <section className="bg-white py-20" id="projects">
+      <div className="mx-auto max-w-6xl px-5">
+        <h2 className="mb-12 text-center text-4xl font-bold">My Projects</h2>
+        <div className="grid gap-8 md:grid-cols-2 lg:grid-cols-3">
+          {projects.map((project, index) => (
+            <div key={index} className="rounded-xl bg-slate-50 p-8 shadow-lg">
+              <h3 className="mb-4 text-lg font-semibold text-primary">{project.title}</h3>
+              <p>{project.description}</p>
+              <div className="my-4 flex flex-wrap gap-2">
+                {project.tech.map((tech, i) => (
+                  <span key={i} className="rounded-full bg-white px-4 py-1 text-sm">{tech}</span>
+                ))}
+              </div>
+              <a href={project.github} className="btn btn-primary" target="_blank" rel="noopener noreferrer">
+                View on GitHub
+              </a>
+            </div>
+          ))}
+        </div>
+      </div>
+    </section>
+  );
+}
+
+export default Projects;
@@ -0,0 +1,14 @@
+import { describe, expect, it } from 'vitest'
+import { mount } from '@vue/test-utils'
+import App from './App.vue'
+
+describe('App', () => {
+  it('renders every section', () => {
+    const wrapper = mount(App)
+
+    expect(wrapper.find('h1').text()).toContain("Hi, I'm")
+    for (const id of ['hero', 'about', 'projects']) {
+      expect(wrapper.find(`#${id}`).exists()).toBe(true)
+    }
+  })
+})
@@ -0,0 +1,14 @@
+import { describe, expect, it } from 'vitest'
+import { mount } from '@vue/test-utils'
+import About from './About.vue'
+
+describe('About', () => {
+  it('lists every skill', () => {
+    const wrapper = mount(About)
+
+    expect(wrapper.findAll('h3').map((heading) => heading.text())).toEqual([
+      'Mathematics',
+      'Translation',
+    ])
+  })
+})
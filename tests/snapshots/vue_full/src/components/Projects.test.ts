@@ -0,0 +1,13 @@
+import { describe, expect, it } from 'vitest'
+import { mount } from '@vue/test-utils'
+import Projects from './Projects.vue'
+
+describe('Projects', () => {
+  it('shows a card for every project', () => {
+    const wrapper = mount(Projects)
+
+    expect(wrapper.findAll('h3').map((heading) => heading.text())).toEqual([
+      'Note G',
+    ])
+  })
+})
@@ -0,0 +1,13 @@
+/** @type {import('tailwindcss').Config} */
+module.exports = {
+  content: ['./index.html', './src/**/*.{vue,js,ts}'],
+  theme: {
+    extend: {
+      colors: {
+        primary: '#42b883',
+        secondary: '#35495e',
+      },
+    },
+  },
+  plugins: [],
+};
@@ -0,0 +1,176 @@
+//! `webgen upgrade` against a small template whose files change between
+//! "versions", covering each way a file can be upgraded.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use webgen::git::{self, Identity};
+use webgen::output::Directory;
+use webgen::render::Variables;
+use webgen::templates::{Template, TemplateFile};
+use webgen::upgrade::{Upgrade, UpgradeSummary};
+use webgen::{Manifest, Options, ProjectPlan};
+
+/// A template generating exactly `files`, as (path, contents).
+struct Versioned(Vec<(&'static str, &'static str)>);
+
+impl Template for Versioned {
+    fn id(&self) -> &str {
+        "versioned"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        Vec::new()
+    }
+
+    fn display_name(&self) -> &str {
+        "Versioned"
+    }
+
+    fn description(&self) -> &str {
+        "Test template"
+    }
+
+    fn files(&self, _vars: &Variables) -> Vec<TemplateFile> {
+        self.0.iter().map(|(path, contents)| TemplateFile::new(*path, *contents)).collect()
+    }
+
+    fn next_steps(&self, _vars: &Variables) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+const APP_V1: &str = "header\none\ntwo\nthree\nfooter\n";
+const APP_V2: &str = "header\none\ntwo\nthree\nfooter v2\n";
+const APP_V3: &str = "header v3\none\ntwo\nthree\nfooter v2\n";
+
+/// An empty directory for `name`, outside any git work tree.
+fn project(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("webgen-upgrade-{}-{}", name, std::process::id()));
+    if dir.exists() {
+        fs::remove_dir_all(&dir).unwrap();
+    }
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn generate(dir: &Path, template: &Versioned) {
+    let plan = ProjectPlan::new("demo", template, &Options::default()).unwrap();
+    plan.render(&mut Directory::new(dir)).unwrap();
+}
+
+/// Commits the generated project, so upgrades can find the generated files.
+fn commit(dir: &Path) {
+    let author = Identity { name: "Test".to_string(), email: "test@example.com".to_string() };
+    git::init(dir, Some(&author)).unwrap();
+}
+
+fn upgrade(dir: &Path, template: &Versioned) -> UpgradeSummary {
+    let manifest = Manifest::load(dir).unwrap();
+    let upgrade = Upgrade::prepare(dir, template, &manifest).unwrap();
+    upgrade.apply(dir).unwrap();
+    upgrade.summary
+}
+
+fn read(dir: &Path, path: &str) -> String {
+    fs::read_to_string(dir.join(path)).unwrap()
+}
+
+#[test]
+fn untouched_files_are_updated_and_new_files_added() {
+    let dir = project("updated");
+    generate(&dir, &Versioned(vec![("app.txt", APP_V1)]));
+
+    let summary = upgrade(&dir, &Versioned(vec![("app.txt", APP_V2), ("new.txt", "new\n")]));
+
+    assert_eq!(summary.updated, ["app.txt"]);
+    assert_eq!(summary.added, ["new.txt"]);
+    assert_eq!(read(&dir, "app.txt"), APP_V2);
+    assert_eq!(read(&dir, "new.txt"), "new\n");
+    assert!(upgrade(&dir, &Versioned(vec![("app.txt", APP_V2), ("new.txt", "new\n")])).is_empty());
+}
+
+#[test]
+fn manifests_record_options_but_not_derived_variables() {
+    let dir = project("manifest");
+    generate(&dir, &Versioned(vec![("app.txt", APP_V1)]));
+
+    let manifest: serde_json::Value = serde_json::from_str(&read(&dir, ".webgen.json")).unwrap();
+
+    let variables: Vec<_> = manifest["variables"].as_object().unwrap().keys().collect();
+    assert_eq!(variables, ["year"]);
+    assert_eq!(manifest["options"]["package_manager"], "npm");
+}
+
+#[test]
+fn customised_files_are_merged_again_on_the_next_upgrade() {
+    let dir = project("merged");
+    generate(&dir, &Versioned(vec![("app.txt", APP_V1)]));
+    commit(&dir);
+    fs::write(dir.join("app.txt"), "header\none\nmine\nthree\nfooter\n").unwrap();
+
+    let summary = upgrade(&dir, &Versioned(vec![("app.txt", APP_V2)]));
+    assert_eq!(summary.merged, ["app.txt"]);
+    assert_eq!(read(&dir, "app.txt"), "header\none\nmine\nthree\nfooter v2\n");
+
+    // The second base (v2 output) was never on disk or committed.
+    let summary = upgrade(&dir, &Versioned(vec![("app.txt", APP_V3)]));
+    assert_eq!(summary.merged, ["app.txt"]);
+    assert!(summary.conflicts.is_empty());
+    assert_eq!(read(&dir, "app.txt"), "header v3\none\nmine\nthree\nfooter v2\n");
+}
+
+#[test]
+fn overlapping_changes_are_left_as_conflicts() {
+    let dir = project("conflict");
+    generate(&dir, &Versioned(vec![("app.txt", APP_V1)]));
+    commit(&dir);
+    fs::write(dir.join("app.txt"), "header\none\ntwo\nthree\nfooter mine\n").unwrap();
+
+    let summary = upgrade(&dir, &Versioned(vec![("app.txt", APP_V2)]));
+
+    assert_eq!(summary.conflicts, ["app.txt"]);
+    let app = read(&dir, "app.txt");
+    assert!(app.starts_with("header\none\ntwo\nthree\n<<<<<<< yours\nfooter mine\n"), "{}", app);
+    assert!(app.contains(&format!(">>>>>>> webgen {}\n", webgen::VERSION)), "{}", app);
+}
+
+#[test]
+fn customised_files_without_a_base_conflict_as_a_whole() {
+    let dir = project("no-base");
+    generate(&dir, &Versioned(vec![("app.txt", APP_V1)]));
+    fs::write(dir.join("app.txt"), "header\none\nmine\nthree\nfooter\n").unwrap();
+
+    let summary = upgrade(&dir, &Versioned(vec![("app.txt", APP_V2)]));
+
+    assert_eq!(summary.conflicts, ["app.txt"]);
+    assert!(read(&dir, "app.txt").starts_with("<<<<<<< yours\nheader\n"));
+}
+
+#[test]
+fn dropped_files_are_removed_unless_customised() {
+    let dir = project("removed");
+    let v1 = Versioned(vec![("app.txt", APP_V1), ("old.txt", "old\n"), ("mine.txt", "mine\n")]);
+    generate(&dir, &v1);
+    fs::write(dir.join("mine.txt"), "mine, edited\n").unwrap();
+
+    let summary = upgrade(&dir, &Versioned(vec![("app.txt", APP_V1)]));
+
+    assert_eq!(summary.removed, ["old.txt"]);
+    assert_eq!(summary.kept, ["mine.txt"]);
+    assert!(!dir.join("old.txt").exists());
+    assert_eq!(read(&dir, "mine.txt"), "mine, edited\n");
+}
+
+#[test]
+fn deleted_files_stay_deleted() {
+    let dir = project("kept");
+    generate(&dir, &Versioned(vec![("app.txt", APP_V1), ("gone.txt", "gone\n")]));
+    fs::remove_file(dir.join("gone.txt")).unwrap();
+
+    let summary = upgrade(&dir, &Versioned(vec![("app.txt", APP_V1), ("gone.txt", "gone v2\n")]));
+
+    assert_eq!(summary.kept, ["gone.txt"]);
+    assert!(!dir.join("gone.txt").exists());
+}